@@ -0,0 +1,128 @@
+use crate::error::TwilioError;
+use crate::Result;
+use url::Url;
+
+/// Builds the callback URLs handed to Twilio (`status_callback`, `recording_status_callback`,
+/// ...) from the public base URL an app is reachable at, so they aren't concatenated by hand.
+///
+/// Paths are resolved relative to the base, keeping any path it has, and every URL produced
+/// must be HTTPS and stay under the base or [`TwilioError::InvalidCallbackUrl`] is returned.
+#[derive(Clone, Debug)]
+pub struct CallbackUrls {
+    base: Url,
+}
+
+impl CallbackUrls {
+    pub fn new(base: Url) -> Result<Self> {
+        let mut base = require_https(base)?;
+        if !base.path().ends_with('/') {
+            let path = format!("{}/", base.path());
+            base.set_path(&path);
+        }
+        Ok(Self { base })
+    }
+
+    pub fn base(&self) -> &Url {
+        &self.base
+    }
+
+    /// Resolves `path` against the base, e.g. `calls/status` under `https://example.com/twilio`
+    /// is `https://example.com/twilio/calls/status`. Absolute URLs and `..` that would leave
+    /// the base are rejected.
+    pub fn url(&self, path: &str) -> Result<Url> {
+        let url = self
+            .base
+            .join(path.trim_start_matches('/'))
+            .map_err(|e| TwilioError::InvalidCallbackUrl(format!("{path}: {e}")))?;
+        let url = require_https(url)?;
+        if url.origin() != self.base.origin() || !url.path().starts_with(self.base.path()) {
+            return Err(TwilioError::InvalidCallbackUrl(format!(
+                "{url} is not under {}",
+                self.base
+            )));
+        }
+        Ok(url)
+    }
+}
+
+/// Errs with [`TwilioError::InvalidCallbackUrl`] unless `url` is an absolute HTTP(S) URL.
+pub(crate) fn require_http(url: &str) -> Result<()> {
+    match Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(()),
+        _ => Err(TwilioError::InvalidCallbackUrl(url.to_string())),
+    }
+}
+
+fn require_https(url: Url) -> Result<Url> {
+    if url.scheme() != "https" {
+        return Err(TwilioError::InvalidCallbackUrl(format!(
+            "{url} must use https"
+        )));
+    }
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn callback_urls_is_building_urls_under_base_path() {
+        let urls = CallbackUrls::new(Url::parse("https://example.com/twilio").unwrap()).unwrap();
+
+        assert_eq!(
+            urls.url("calls/status").unwrap().as_str(),
+            "https://example.com/twilio/calls/status"
+        );
+        assert_eq!(
+            urls.url("/recordings/status?tenant=42").unwrap().as_str(),
+            "https://example.com/twilio/recordings/status?tenant=42"
+        );
+
+        let urls = CallbackUrls::new(Url::parse("https://example.com").unwrap()).unwrap();
+        assert_eq!(urls.url("amd").unwrap().as_str(), "https://example.com/amd");
+    }
+
+    #[test]
+    fn callback_urls_is_erring_when_leaving_base() {
+        let urls = CallbackUrls::new(Url::parse("https://example.com/twilio").unwrap()).unwrap();
+
+        for path in [
+            "https://other.example/x",
+            "https://example.com/admin",
+            "../admin",
+        ] {
+            assert!(matches!(
+                urls.url(path),
+                Err(TwilioError::InvalidCallbackUrl(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn require_http_is_accepting_only_http_urls() {
+        assert!(require_http("https://example.com/status").is_ok());
+        assert!(require_http("http://example.com/status").is_ok());
+
+        for url in [
+            "/status",
+            "ftp://example.com/status",
+            "wss://example.com/status",
+        ] {
+            assert!(matches!(
+                require_http(url),
+                Err(TwilioError::InvalidCallbackUrl(got)) if got == url
+            ));
+        }
+    }
+
+    #[test]
+    fn callback_urls_is_erring_when_not_https() {
+        let got = CallbackUrls::new(Url::parse("http://example.com").unwrap());
+        assert!(matches!(got, Err(TwilioError::InvalidCallbackUrl(_))));
+
+        let urls = CallbackUrls::new(Url::parse("https://example.com").unwrap()).unwrap();
+        let got = urls.url("http://example.org/status");
+        assert!(matches!(got, Err(TwilioError::InvalidCallbackUrl(_))));
+    }
+}
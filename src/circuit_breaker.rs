@@ -0,0 +1,117 @@
+use crate::error::TwilioError;
+use crate::Result;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Short-circuits requests with [`TwilioError::CircuitOpen`] after `failure_threshold`
+/// consecutive failures, for `cooldown`, so a sustained Twilio outage isn't made worse.
+///
+/// Transport errors, 5xx and 429 responses count as failures; any other response resets the
+/// count. Once the cooldown elapses the next request is let through, and a further failure
+/// reopens the breaker straight away. Clones share state.
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    state: Arc<Mutex<BreakerState>>,
+}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold,
+            cooldown,
+            state: Arc::new(Mutex::new(BreakerState::default())),
+        }
+    }
+
+    pub fn failure_threshold(&self) -> u32 {
+        self.failure_threshold
+    }
+
+    pub fn cooldown(&self) -> Duration {
+        self.cooldown
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open_at(Instant::now())
+    }
+
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.is_open() {
+            return Err(TwilioError::CircuitOpen);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn record_success(&self) {
+        let mut state = self.state();
+        state.consecutive_failures = 0;
+        state.opened_at = None;
+    }
+
+    pub(crate) fn record_failure(&self) {
+        self.record_failure_at(Instant::now())
+    }
+
+    fn is_open_at(&self, now: Instant) -> bool {
+        self.state()
+            .opened_at
+            .is_some_and(|opened_at| now.duration_since(opened_at) < self.cooldown)
+    }
+
+    fn record_failure_at(&self, now: Instant) {
+        let mut state = self.state();
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        if state.consecutive_failures >= self.failure_threshold {
+            state.opened_at = Some(now);
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circuit_breaker_is_opening_after_threshold_and_closing_after_cooldown() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(30));
+        let start = Instant::now();
+
+        breaker.record_failure_at(start);
+        breaker.record_failure_at(start);
+        assert!(!breaker.is_open_at(start));
+
+        breaker.record_failure_at(start);
+        assert!(breaker.is_open_at(start));
+        assert!(breaker.is_open_at(start + Duration::from_secs(29)));
+        assert!(!breaker.is_open_at(start + Duration::from_secs(30)));
+
+        // a failure after the cooldown reopens it without waiting for the threshold again
+        let retry = start + Duration::from_secs(31);
+        breaker.record_failure_at(retry);
+        assert!(breaker.is_open_at(retry));
+
+        breaker.record_success();
+        assert!(!breaker.is_open_at(retry));
+        assert!(breaker.check().is_ok());
+    }
+
+    #[test]
+    fn circuit_breaker_check_is_erring_when_open() {
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(30));
+        breaker.record_failure();
+
+        assert!(matches!(breaker.check(), Err(TwilioError::CircuitOpen)));
+    }
+}
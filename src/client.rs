@@ -1,14 +1,21 @@
 #![allow(dead_code)]
-use crate::endpoints::TwilioEndpoint;
+use crate::callback_urls::CallbackUrls;
+use crate::circuit_breaker::CircuitBreaker;
+use crate::edge::{AutoEdge, Edge};
+use crate::endpoints::accounts::FetchAccount;
+use crate::endpoints::{Paginated, TwilioEndpoint};
 use crate::error::*;
+use crate::item_stream::ItemStream;
+use crate::retry::RetryPolicy;
 use crate::validation::*;
 use crate::Result;
-use http::{HeaderMap, Method, Uri};
+use http::header::ACCEPT;
+use http::{HeaderMap, Method, StatusCode, Uri};
+use serde::de::DeserializeOwned;
 use std::collections::BTreeMap;
+use std::time::Duration;
 use url::Url;
 
-const APPLICATION_JSON: &str = "application/json";
-
 #[derive(Clone, Debug)]
 pub struct TwilioClient {
     inner: reqwest::Client,
@@ -18,6 +25,15 @@ pub struct TwilioClient {
     main_api_key_secret: Option<String>,
     number: Option<String>,
     base_url: Url,
+    /// Set by [`TwilioClient::with_base_url`] and [`TwilioClient::with_edge`], which win over
+    /// [`TwilioClient::with_auto_edge`].
+    explicit_base_url: bool,
+    auto_edge: Option<AutoEdge>,
+    circuit_breaker: Option<CircuitBreaker>,
+    retry_policy: Option<RetryPolicy>,
+    callback_urls: Option<CallbackUrls>,
+    max_response_bytes: Option<usize>,
+    timeout: Option<Duration>,
 }
 
 impl TwilioClient {
@@ -31,7 +47,7 @@ impl TwilioClient {
 
     pub fn from_env() -> Result<Self> {
         Ok(Self {
-            inner: reqwest::Client::new(),
+            inner: http_client(None),
             account_sid: std::env::var("TWILIO_ACCOUNT_SID")
                 .map_err(|_| TwilioError::MissingAccountSidEnvVar)?,
             auth_token: std::env::var("TWILIO_AUTH_TOKEN")
@@ -40,30 +56,92 @@ impl TwilioClient {
             main_api_key_secret: std::env::var("TWILIO_MAIN_API_KEY_SECRET").ok(),
             number: std::env::var("TWILIO_PHONE_NUMBER").ok(),
             base_url: Url::parse("https://api.twilio.com").unwrap(),
+            explicit_base_url: false,
+            auto_edge: None,
+            circuit_breaker: None,
+            retry_policy: None,
+            callback_urls: None,
+            max_response_bytes: None,
+            timeout: None,
         })
     }
 
     pub fn new(account_sid: impl Into<String>, auth_token: impl Into<String>) -> Self {
         Self {
-            inner: reqwest::Client::new(),
+            inner: http_client(None),
             account_sid: account_sid.into(),
             auth_token: auth_token.into(),
             main_api_key: None,
             main_api_key_secret: None,
             number: None,
             base_url: Url::parse("https://api.twilio.com").unwrap(),
+            explicit_base_url: false,
+            auto_edge: None,
+            circuit_breaker: None,
+            retry_policy: None,
+            callback_urls: None,
+            max_response_bytes: None,
+            timeout: None,
         }
     }
 
+    /// Sends `endpoint` and parses its response.
+    ///
+    /// An endpoint built without an account SID, e.g. with
+    /// [`FetchCall::on`](crate::endpoints::voice::call::FetchCall::on), is sent for this
+    /// client's account.
+    ///
+    /// 307 and 308 redirects are followed with the same method and body, since form and
+    /// JSON bodies are buffered and can be replayed.
     pub async fn hit<E: TwilioEndpoint>(&self, endpoint: E) -> Result<E::ResponseBody> {
+        let resp = self.request(endpoint).await?;
+        E::response_body(resp).await
+    }
+
+    /// Like [`TwilioClient::hit`] for a list endpoint, but parses the page's items one at a
+    /// time as the body arrives rather than buffering it, see [`ItemStream`].
+    ///
+    /// With [`TwilioClient::with_max_response_bytes`] set the body is still buffered up to
+    /// the limit to enforce it.
+    pub async fn hit_streaming<E>(&self, endpoint: E) -> Result<ItemStream<E::ResponseBody>>
+    where
+        E: TwilioEndpoint,
+        E::ResponseBody: Paginated,
+        <E::ResponseBody as Paginated>::Item: DeserializeOwned,
+    {
+        let resp = self.request(endpoint).await?;
+        Ok(ItemStream::new(resp))
+    }
+
+    /// Sends `endpoint`, returning the response once it's known to be successful.
+    async fn request<E: TwilioEndpoint>(&self, mut endpoint: E) -> Result<reqwest::Response> {
+        if let Some(account_sid) = endpoint.account_sid_mut().filter(|sid| sid.is_empty()) {
+            account_sid.clone_from(&self.account_sid);
+        }
+
         let mut builder = self
             .inner
-            .request(E::METHOD, endpoint.url(&self.base_url))
+            .request(E::METHOD, endpoint.url(&self.current_base_url()))
+            .header(ACCEPT, E::ACCEPT)
             .basic_auth(&self.account_sid, Some(&self.auth_token));
 
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
         builder = endpoint.configure_request_body(builder)?;
 
-        let resp = builder.send().await?;
+        let mut resp = match &self.retry_policy {
+            Some(retry_policy) => {
+                self.send_with_retries(&E::METHOD, builder, retry_policy)
+                    .await?
+            }
+            None => self.send(builder).await?,
+        };
+
+        if let Some(limit) = self.max_response_bytes {
+            resp = limit_body(resp, limit).await?;
+        }
 
         if !resp.status().is_success() {
             let status = resp.status();
@@ -71,7 +149,79 @@ impl TwilioClient {
             return Err(TwilioError::Api { status, error });
         }
 
-        E::response_body(resp).await
+        Ok(resp)
+    }
+
+    fn current_base_url(&self) -> Url {
+        let Some(auto_edge) = &self.auto_edge else {
+            return self.base_url.clone();
+        };
+        match auto_edge.chosen() {
+            Some((_, url)) => url,
+            None => {
+                auto_edge.measure_in_background(&self.inner);
+                self.base_url.clone()
+            }
+        }
+    }
+
+    async fn send(&self, builder: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        if let Some(breaker) = &self.circuit_breaker {
+            breaker.check()?;
+        }
+
+        let resp = builder.send().await;
+
+        if let Some(breaker) = &self.circuit_breaker {
+            match &resp {
+                Ok(resp)
+                    if !resp.status().is_server_error()
+                        && resp.status() != StatusCode::TOO_MANY_REQUESTS =>
+                {
+                    breaker.record_success()
+                }
+                _ => breaker.record_failure(),
+            }
+        }
+
+        Ok(resp?)
+    }
+
+    async fn send_with_retries(
+        &self,
+        method: &Method,
+        builder: reqwest::RequestBuilder,
+        retry_policy: &RetryPolicy,
+    ) -> Result<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            tokio::time::sleep(retry_policy.pacing_delay()).await;
+
+            // multipart bodies are streamed, so they can't be resent
+            let Some(request) = builder.try_clone() else {
+                return self.send(builder).await;
+            };
+            let resp = self.send(request).await?;
+            retry_policy.observe(resp.headers());
+
+            if !retry_policy.should_retry(method, resp.status(), attempt) {
+                return Ok(resp);
+            }
+            tokio::time::sleep(retry_policy.backoff(attempt, resp.headers())).await;
+            attempt += 1;
+        }
+    }
+
+    /// Fetches this client's account as a health check, erring with
+    /// [`TwilioError::InvalidCredentials`] if Twilio rejects the credentials.
+    pub async fn verify_credentials(&self) -> Result<()> {
+        match self.hit(FetchAccount::new(&self.account_sid)).await {
+            Ok(_) => Ok(()),
+            Err(TwilioError::Api { status, .. }) if status == StatusCode::UNAUTHORIZED => {
+                Err(TwilioError::InvalidCredentials)
+            }
+            Err(e) => Err(e),
+        }
     }
 
     pub fn number(&self) -> Option<&str> {
@@ -82,8 +232,95 @@ impl TwilioClient {
         self
     }
 
+    /// Sends requests to `base_url`, turning off [`TwilioClient::with_auto_edge`].
     pub fn with_base_url(mut self, base_url: Url) -> Self {
         self.base_url = base_url;
+        self.explicit_base_url = true;
+        self.auto_edge = None;
+        self
+    }
+
+    /// Sends requests through `edge` instead of letting Twilio route them, turning off
+    /// [`TwilioClient::with_auto_edge`].
+    pub fn with_edge(self, edge: Edge) -> Self {
+        self.with_base_url(edge.base_url())
+    }
+
+    /// Sends requests through whichever [`Edge`] answers fastest from here, measured once in
+    /// the background and then kept for this client and its clones.
+    ///
+    /// Nothing waits for the measurement: it starts straight away inside a Tokio runtime,
+    /// otherwise on the first request, and requests go to the base URL until it's done. Use
+    /// [`TwilioClient::detect_edge`] to wait for it instead.
+    ///
+    /// Does nothing once a base URL or edge has been set explicitly.
+    pub fn with_auto_edge(mut self) -> Self {
+        if self.explicit_base_url {
+            return self;
+        }
+        let auto_edge = AutoEdge::new();
+        auto_edge.measure_in_background(&self.inner);
+        self.auto_edge = Some(auto_edge);
+        self
+    }
+
+    /// The edge chosen by [`TwilioClient::with_auto_edge`], once measured.
+    pub fn edge(&self) -> Option<Edge> {
+        self.auto_edge
+            .as_ref()
+            .and_then(AutoEdge::chosen)
+            .map(|(edge, _)| edge)
+    }
+
+    /// Measures the edges now and keeps the fastest, `None` if auto edge selection is off or
+    /// no edge answered.
+    pub async fn detect_edge(&self) -> Option<Edge> {
+        self.auto_edge.as_ref()?.measure(&self.inner).await
+    }
+
+    /// Limits each request, from connecting until the response body has been read.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Limits only connecting to Twilio, so network trouble fails fast while slow responses
+    /// are still allowed up to [`TwilioClient::with_timeout`].
+    ///
+    /// # Panics
+    ///
+    /// Like [`reqwest::Client::new`], if the TLS backend can't be initialized.
+    pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.inner = http_client(Some(connect_timeout));
+        self
+    }
+
+    /// Rejects responses whose body is over `limit` bytes with
+    /// [`TwilioError::ResponseTooLarge`], checking `Content-Length` up front and counting
+    /// bytes as they stream in so an oversized body is never fully buffered.
+    pub fn with_max_response_bytes(mut self, limit: usize) -> Self {
+        self.max_response_bytes = Some(limit);
+        self
+    }
+
+    /// Sets the public base URL callbacks are built from, see [`CallbackUrls`].
+    pub fn with_callback_base(mut self, base: Url) -> Result<Self> {
+        self.callback_urls = Some(CallbackUrls::new(base)?);
+        Ok(self)
+    }
+
+    pub fn callback_urls(&self) -> Option<&CallbackUrls> {
+        self.callback_urls.as_ref()
+    }
+
+    pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Retries throttled and failed requests, see [`RetryPolicy`].
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
         self
     }
 
@@ -93,9 +330,22 @@ impl TwilioClient {
         uri: &Uri,
         headers: &HeaderMap,
         post_params: Option<&BTreeMap<String, String>>,
+    ) -> Result<()> {
+        self.validate_request_with_token(&self.auth_token, method, uri, headers, post_params)
+    }
+
+    /// Validates a webhook signed with `auth_token` rather than this client's, e.g. a
+    /// subaccount's webhook received by a parent account's client.
+    pub fn validate_request_with_token(
+        &self,
+        auth_token: &str,
+        method: &Method,
+        uri: &Uri,
+        headers: &HeaderMap,
+        post_params: Option<&BTreeMap<String, String>>,
     ) -> Result<()> {
         Ok(validate_twilio_signature(
-            &self.auth_token,
+            auth_token,
             method,
             uri,
             headers,
@@ -103,3 +353,477 @@ impl TwilioClient {
         )?)
     }
 }
+
+/// Builds the HTTP client every request goes through. Client-wide settings are all applied
+/// here, so replacing the client never loses one.
+fn http_client(connect_timeout: Option<Duration>) -> reqwest::Client {
+    let mut builder = reqwest::Client::builder();
+    if let Some(connect_timeout) = connect_timeout {
+        builder = builder.connect_timeout(connect_timeout);
+    }
+    builder.build().expect("failed to build reqwest client")
+}
+
+/// Buffers `resp`'s body up to `limit` bytes and hands back an equivalent response.
+async fn limit_body(mut resp: reqwest::Response, limit: usize) -> Result<reqwest::Response> {
+    let too_large = TwilioError::ResponseTooLarge { limit };
+    if resp.content_length().is_some_and(|len| len > limit as u64) {
+        return Err(too_large);
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = resp.chunk().await? {
+        if body.len() + chunk.len() > limit {
+            return Err(too_large);
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    let mut limited = http::Response::new(body);
+    *limited.status_mut() = resp.status();
+    *limited.version_mut() = resp.version();
+    *limited.headers_mut() = resp.headers().clone();
+    Ok(limited.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::messaging::media::FetchMessageMediaContent;
+    use crate::endpoints::voice::call::{
+        CallStatus, CreateCall, CreateCallBody, FetchCall, ListCalls,
+    };
+    use crate::mock_server::{MockResponse, MockServer};
+    use crate::TwilioQuery;
+    use base64::Engine;
+    use hmac::{Hmac, Mac};
+
+    fn sign(auth_token: &str, url: &str) -> String {
+        let mut mac = Hmac::<sha1::Sha1>::new_from_slice(auth_token.as_bytes()).unwrap();
+        mac.update(url.as_bytes());
+        base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes())
+    }
+
+    #[test]
+    fn validate_request_with_token_is_using_given_token() {
+        let client = TwilioClient::new("AC_parent", "parent_token");
+        let url = "https://example.com/webhook?CallSid=CA123";
+        let uri = Uri::from_static("https://example.com/webhook?CallSid=CA123");
+
+        let mut headers = HeaderMap::new();
+        headers.insert("Host", "example.com".parse().unwrap());
+        headers.insert(
+            "X-Twilio-Signature",
+            sign("subaccount_token", url).parse().unwrap(),
+        );
+
+        assert!(client
+            .validate_request_with_token("subaccount_token", &Method::GET, &uri, &headers, None)
+            .is_ok());
+        assert!(matches!(
+            client.validate_request(&Method::GET, &uri, &headers, None),
+            Err(TwilioError::Validation(
+                SignatureValidationError::InvalidSignature
+            ))
+        ));
+    }
+
+    #[test]
+    fn with_callback_base_is_building_callback_urls() {
+        let client = TwilioClient::new("AC123", "token")
+            .with_callback_base(Url::parse("https://example.com/hooks/").unwrap())
+            .unwrap();
+        let urls = client.callback_urls().unwrap();
+
+        assert_eq!(
+            urls.url("status").unwrap().as_str(),
+            "https://example.com/hooks/status"
+        );
+        assert!(matches!(
+            TwilioClient::new("AC123", "token")
+                .with_callback_base(Url::parse("http://example.com").unwrap()),
+            Err(TwilioError::InvalidCallbackUrl(_))
+        ));
+    }
+
+    const CALL: &str = r#"{"sid": "CA123", "account_sid": "AC123", "to": "+15558675310", "from": "+15017122661", "uri": "/uri"}"#;
+
+    #[tokio::test]
+    async fn hit_is_erring_when_content_length_is_over_max_response_bytes() {
+        let server = MockServer::sequence(vec![
+            MockResponse::json(200, CALL).with_header("Content-Length", "1000000")
+        ]);
+        let client = TwilioClient::new("AC123", "token")
+            .with_base_url(server.url())
+            .with_max_response_bytes(1024);
+
+        let got = client.hit(FetchCall::new("AC123", "CA123")).await;
+
+        assert!(matches!(
+            got,
+            Err(TwilioError::ResponseTooLarge { limit: 1024 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn hit_is_accepting_body_exactly_at_max_response_bytes() {
+        let server = MockServer::sequence(vec![MockResponse::json(200, CALL)]);
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+
+        let limited = client.clone().with_max_response_bytes(CALL.len() - 1);
+        let got = limited.hit(FetchCall::new("AC123", "CA123")).await;
+        assert!(matches!(got, Err(TwilioError::ResponseTooLarge { .. })));
+
+        let limited = client.with_max_response_bytes(CALL.len());
+        let got = limited.hit(FetchCall::new("AC123", "CA123")).await.unwrap();
+        assert_eq!(got.sid, "CA123");
+    }
+
+    #[tokio::test]
+    async fn hit_is_using_client_account_sid_when_endpoint_has_none() {
+        let server = MockServer::sequence(vec![MockResponse::json(200, CALL)]);
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+
+        client.hit(FetchCall::on("CA123")).await.unwrap();
+        client.hit(FetchCall::new("AC456", "CA123")).await.unwrap();
+
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/2010-04-01/Accounts/AC123/Calls/CA123.json",
+                "/2010-04-01/Accounts/AC456/Calls/CA123.json",
+            ]
+        );
+    }
+
+    #[test]
+    fn with_base_url_is_winning_over_auto_edge() {
+        let url = Url::parse("https://example.com").unwrap();
+
+        let client = TwilioClient::new("AC123", "token")
+            .with_auto_edge()
+            .with_base_url(url.clone());
+        assert!(client.auto_edge.is_none());
+        assert_eq!(client.current_base_url(), url);
+
+        let client = TwilioClient::new("AC123", "token")
+            .with_edge(Edge::Dublin)
+            .with_auto_edge();
+        assert!(client.auto_edge.is_none());
+        assert_eq!(client.current_base_url(), Edge::Dublin.base_url());
+    }
+
+    #[tokio::test]
+    async fn detect_edge_is_measuring_once_when_called_concurrently() {
+        let edge = MockServer::start(|_| {
+            std::thread::sleep(Duration::from_millis(100));
+            MockResponse::json(200, CALL)
+        });
+        let mut client = TwilioClient::new("AC123", "token");
+        client.auto_edge = Some(AutoEdge::with_candidates(vec![(Edge::Sydney, edge.url())]));
+
+        let (first, second) = tokio::join!(client.detect_edge(), client.detect_edge());
+
+        assert_eq!(first, Some(Edge::Sydney));
+        assert_eq!(second, Some(Edge::Sydney));
+        assert_eq!(edge.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn detect_edge_is_choosing_fastest_edge() {
+        let base = MockServer::sequence(vec![MockResponse::json(200, CALL)]);
+        let slow = MockServer::start(|_| {
+            std::thread::sleep(Duration::from_millis(300));
+            MockResponse::json(200, CALL)
+        });
+        let fast = MockServer::sequence(vec![MockResponse::json(200, CALL)]);
+        // stands in for the default base URL, which with_base_url would pin
+        let mut client = TwilioClient::new("AC123", "token");
+        client.base_url = base.url();
+        client.auto_edge = Some(AutoEdge::with_candidates(vec![
+            (Edge::Dublin, slow.url()),
+            (Edge::Sydney, fast.url()),
+            (Edge::Tokyo, Url::parse("http://127.0.0.1:1").unwrap()),
+        ]));
+
+        // requests don't wait for the measurement
+        assert_eq!(client.edge(), None);
+        client.hit(FetchCall::new("AC123", "CA123")).await.unwrap();
+        assert_eq!(base.requests().len(), 1);
+
+        assert_eq!(client.detect_edge().await, Some(Edge::Sydney));
+        assert_eq!(client.clone().edge(), Some(Edge::Sydney));
+
+        client.hit(FetchCall::new("AC123", "CA123")).await.unwrap();
+        assert!(fast
+            .requests()
+            .iter()
+            .any(|r| r.path == "/2010-04-01/Accounts/AC123/Calls/CA123.json"));
+    }
+
+    #[tokio::test]
+    async fn hit_is_retrying_throttled_request() {
+        let server = MockServer::sequence(vec![
+            MockResponse::json(
+                429,
+                r#"{"code": 20429, "message": "Too Many Requests", "more_info": "", "status": 429}"#,
+            ),
+            MockResponse::json(200, CALL),
+        ]);
+        let client = TwilioClient::new("AC123", "token")
+            .with_base_url(server.url())
+            .with_retry_policy(RetryPolicy::new(2, Duration::from_millis(1)));
+
+        let got = client.hit(FetchCall::new("AC123", "CA123")).await.unwrap();
+
+        assert_eq!(got.sid, "CA123");
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn hit_is_returning_error_when_retries_run_out() {
+        let server = MockServer::sequence(vec![MockResponse::json(
+            503,
+            r#"{"code": 20503, "message": "Service Unavailable", "more_info": "", "status": 503}"#,
+        )]);
+        let client = TwilioClient::new("AC123", "token")
+            .with_base_url(server.url())
+            .with_retry_policy(RetryPolicy::new(2, Duration::from_millis(1)));
+
+        let got = client.hit(FetchCall::new("AC123", "CA123")).await;
+
+        assert!(matches!(
+            got,
+            Err(TwilioError::Api { status, .. }) if status == StatusCode::SERVICE_UNAVAILABLE
+        ));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn hit_is_not_retrying_post_on_internal_server_error() {
+        let server = MockServer::sequence(vec![MockResponse::json(
+            500,
+            r#"{"code": 20500, "message": "Internal Server Error", "more_info": "", "status": 500}"#,
+        )]);
+        let client = TwilioClient::new("AC123", "token")
+            .with_base_url(server.url())
+            .with_retry_policy(RetryPolicy::new(2, Duration::from_millis(1)));
+        let body = CreateCallBody::new("+15558675310", "+15017122661", "https://example.com");
+
+        let got = client.hit(CreateCall::new("AC123", body)).await;
+
+        assert!(matches!(got, Err(TwilioError::Api { .. })));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn hit_is_resending_post_body_on_temporary_redirect() {
+        let server = MockServer::start(|req| {
+            if req.path.starts_with("/2010-04-01/") {
+                MockResponse::json(307, "").with_header("Location", "/redirected/Calls.json")
+            } else {
+                MockResponse::json(201, CALL)
+            }
+        });
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+        let body = CreateCallBody::new("+15558675310", "+15017122661", "https://example.com");
+
+        let got = client.hit(CreateCall::new("AC123", body)).await.unwrap();
+        assert_eq!(got.sid, "CA123");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        let (original, redirected) = (&requests[0], &requests[1]);
+        assert_eq!(redirected.path, "/redirected/Calls.json");
+        assert_eq!(redirected.method, "POST");
+        assert_eq!(redirected.body, original.body);
+        assert_eq!(
+            redirected.header("Authorization"),
+            original.header("Authorization")
+        );
+    }
+
+    #[tokio::test]
+    async fn hit_is_sending_accept_header() {
+        let server = MockServer::sequence(vec![
+            MockResponse::json(200, CALL),
+            MockResponse::json(200, "media"),
+        ]);
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+
+        client.hit(FetchCall::new("AC123", "CA123")).await.unwrap();
+        let media = FetchMessageMediaContent::new("AC123", "MM123", "ME123");
+        assert_eq!(client.hit(media).await.unwrap().data, b"media");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].header("Accept"), Some("application/json"));
+        assert_eq!(requests[1].header("Accept"), Some("*/*"));
+    }
+
+    #[tokio::test]
+    async fn hit_is_fetching_call() {
+        let server = MockServer::sequence(vec![MockResponse::json(200, CALL)]);
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+
+        let got = client.hit(FetchCall::new("AC123", "CA123")).await.unwrap();
+        assert_eq!(got.sid, "CA123");
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/2010-04-01/Accounts/AC123/Calls/CA123.json");
+        assert_eq!(
+            request.header("Authorization"),
+            Some("Basic QUMxMjM6dG9rZW4=")
+        );
+    }
+
+    #[tokio::test]
+    async fn hit_is_creating_call_with_form_body() {
+        let server = MockServer::sequence(vec![MockResponse::json(201, CALL)]);
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+        let body = CreateCallBody::new("+15558675310", "+15017122661", "https://example.com");
+
+        let got = client.hit(CreateCall::new("AC123", body)).await.unwrap();
+        assert_eq!(got.to, "+15558675310");
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/2010-04-01/Accounts/AC123/Calls.json");
+        assert_eq!(
+            request.header("Content-Type"),
+            Some("application/x-www-form-urlencoded")
+        );
+        assert_eq!(
+            request.body,
+            "To=%2B15558675310&From=%2B15017122661&Url=https%3A%2F%2Fexample.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn hit_is_listing_calls_with_query() {
+        let page = format!(
+            r#"{{"calls": [{CALL}], "page": 0, "page_size": 20, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}}"#
+        );
+        let server = MockServer::sequence(vec![MockResponse::json(200, page)]);
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+        let query = TwilioQuery::new()
+            .with_page_size(20)
+            .with_call_status(CallStatus::Completed);
+
+        let got = client.hit(ListCalls::new("AC123", query)).await.unwrap();
+        assert_eq!(got.calls.len(), 1);
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "GET");
+        assert_eq!(
+            request.path,
+            "/2010-04-01/Accounts/AC123/Calls.json?PageSize=20&Status=completed"
+        );
+    }
+
+    #[tokio::test]
+    async fn hit_streaming_is_yielding_same_items_as_buffered_page() {
+        let calls: Vec<_> = (0..5000)
+            .map(|i| {
+                format!(
+                    r#"{{"sid": "CA{i}", "account_sid": "AC123", "to": "+15558675310", "from": "+15017122661", "uri": "/uri", "annotation": "{}"}}"#,
+                    "x".repeat(100)
+                )
+            })
+            .collect();
+        let page = format!(
+            r#"{{"calls": [{}], "page": 0, "page_size": 5000, "first_page_uri": "/first", "end": 4999, "start": 0, "uri": "/uri", "next_page_uri": "/next", "previous_page_uri": null}}"#,
+            calls.join(", ")
+        );
+        assert!(page.len() > 500_000);
+        let server = MockServer::sequence(vec![MockResponse::json(200, page)]);
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+
+        let buffered = client
+            .hit(ListCalls::new("AC123", TwilioQuery::new()))
+            .await
+            .unwrap();
+
+        let mut stream = client
+            .hit_streaming(ListCalls::new("AC123", TwilioQuery::new()))
+            .await
+            .unwrap();
+        assert!(stream.pagination().is_none());
+        let mut streamed = Vec::new();
+        while let Some(call) = stream.next().await {
+            streamed.push(call.unwrap().sid);
+        }
+
+        let want: Vec<_> = buffered.calls.into_iter().map(|call| call.sid).collect();
+        assert_eq!(streamed, want);
+        assert_eq!(
+            stream.pagination().unwrap().next_page_uri.as_deref(),
+            Some("/next")
+        );
+        // only the page's own fields were kept, not the items
+        assert!(stream.scanner_rest_len() < 512);
+    }
+
+    #[tokio::test]
+    async fn hit_is_returning_api_error_on_bad_request() {
+        let server = MockServer::sequence(vec![MockResponse::json(
+            400,
+            r#"{"code": 21205, "message": "Url is not a valid URL", "more_info": "https://www.twilio.com/docs/errors/21205", "status": 400}"#,
+        )]);
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+        let body = CreateCallBody::new("+15558675310", "+15017122661", "not a url");
+
+        let got = client.hit(CreateCall::new("AC123", body)).await;
+
+        match got {
+            Err(TwilioError::Api { status, error }) => {
+                assert_eq!(status, StatusCode::BAD_REQUEST);
+                assert_eq!(
+                    error.to_string(),
+                    "Url is not a valid URL (code 21205), see https://www.twilio.com/docs/errors/21205"
+                );
+            }
+            other => panic!("expected an api error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_credentials_is_checking_account_fetch() {
+        let account = r#"{"auth_token": "token", "date_created": "now", "date_updated": "now", "friendly_name": "name", "owner_account_sid": "AC123", "sid": "AC123", "status": "active", "type": "Full", "uri": "/uri"}"#;
+        let server = MockServer::sequence(vec![
+            MockResponse::json(200, account),
+            MockResponse::json(
+                401,
+                r#"{"code": 20003, "message": "Authenticate", "more_info": "https://www.twilio.com/docs/errors/20003", "status": 401}"#,
+            ),
+        ]);
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+
+        assert!(client.verify_credentials().await.is_ok());
+        assert!(matches!(
+            client.verify_credentials().await,
+            Err(TwilioError::InvalidCredentials)
+        ));
+        assert_eq!(server.requests()[0].path, "/2010-04-01/Accounts/AC123.json");
+    }
+
+    #[tokio::test]
+    async fn hit_is_applying_timeout_separately_from_connect_timeout() {
+        let server = MockServer::start(|_| {
+            std::thread::sleep(Duration::from_millis(300));
+            MockResponse::json(200, CALL)
+        });
+        let client = TwilioClient::new("AC123", "token")
+            .with_base_url(server.url())
+            .with_connect_timeout(Duration::from_secs(1))
+            .with_timeout(Duration::from_millis(50));
+        assert_eq!(client.timeout, Some(Duration::from_millis(50)));
+
+        let got = client.hit(FetchCall::new("AC123", "CA123")).await;
+        assert!(matches!(got, Err(TwilioError::Request(ref e)) if e.is_timeout()));
+
+        let client = client.with_timeout(Duration::from_secs(5));
+        assert!(client.hit(FetchCall::new("AC123", "CA123")).await.is_ok());
+    }
+}
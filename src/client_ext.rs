@@ -1,8 +1,31 @@
 #![allow(dead_code)]
-use crate::endpoints::voice::call::{CreateCall, CreateCallBody, UpdateCall, UpdateCallBody};
+use crate::endpoints::lookups::{LookupField, LookupPhoneNumber, LookupResponse};
+use crate::endpoints::messaging::media::{FetchMessageMediaContent, MediaContent};
+use crate::endpoints::voice::call::{
+    CallDirection, CallResponse, CreateCall, CreateCallBody, FetchCall, UpdateCall, UpdateCallBody,
+    UpdateCallStatus,
+};
+use crate::endpoints::voice::conference::{
+    CreateParticipant, CreateParticipantBody, FetchParticipant, ListParticipants,
+    ParticipantResponse, ParticipantStatus, TwilioRegion,
+};
 use crate::endpoints::TwilioEndpoint;
-use crate::{Result, TwilioClient};
+use crate::error::TwilioError;
+use crate::twiml::voice::{Play, VoiceResponse};
+use crate::{Result, TwilioClient, TwilioQuery};
 use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+
+/// How many requests the batch helpers, e.g. [`TwilioClientExt::create_calls`], keep in
+/// flight at once.
+pub const CREATE_CALLS_CONCURRENCY: usize = 10;
+
+/// How many lookups [`TwilioClientExt::lookup_phone_numbers`] keeps in flight at once.
+pub const LOOKUP_CONCURRENCY: usize = 10;
+
+/// How often [`TwilioClientExt::create_participant_and_wait`] fetches the new leg.
+const PARTICIPANT_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 // Trait definition for common Twilio operations
 pub trait TwilioClientExt {
@@ -11,26 +34,107 @@ pub trait TwilioClientExt {
         to: &str,
         from: &str,
         url: &str,
-    ) -> impl Future<Output = Result<<CreateCall as TwilioEndpoint>::ResponseBody>>;
+    ) -> impl Future<Output = Result<<CreateCall<'_> as TwilioEndpoint>::ResponseBody>>;
 
     fn create_call_with_url(
         &self,
         to: &str,
         from: &str,
         url: &str,
-    ) -> impl Future<Output = Result<<CreateCall as TwilioEndpoint>::ResponseBody>>;
+    ) -> impl Future<Output = Result<<CreateCall<'_> as TwilioEndpoint>::ResponseBody>>;
+
+    /// Creates a call to each of `recipients` executing the TwiML at `url`, with at most
+    /// [`CREATE_CALLS_CONCURRENCY`] requests in flight. Results are in recipient order, so a
+    /// failed call doesn't stop the rest of the batch.
+    fn create_calls(
+        &self,
+        recipients: &[&str],
+        from: &str,
+        url: &str,
+    ) -> impl Future<Output = Vec<Result<CallResponse>>>;
+
+    /// Adds a participant for each `(to, region)` target, with Twilio mixing each one's
+    /// audio in its region. Runs concurrently like [`TwilioClientExt::create_calls`].
+    fn create_participants_in_regions(
+        &self,
+        conference_sid: &str,
+        from: &str,
+        targets: &[(&str, TwilioRegion)],
+    ) -> impl Future<Output = Vec<Result<ParticipantResponse>>>;
+
+    /// Looks up each of `phone_numbers` with `fields`, e.g. to validate a batch of user
+    /// input, with up to [`LOOKUP_CONCURRENCY`] lookups in flight.
+    fn lookup_phone_numbers(
+        &self,
+        phone_numbers: &[&str],
+        fields: &[LookupField],
+    ) -> impl Future<Output = Vec<Result<LookupResponse>>>;
 
     fn update_call_with_twiml(
         &self,
         call_sid: &str,
         twiml: &str,
-    ) -> impl Future<Output = Result<<UpdateCall as TwilioEndpoint>::ResponseBody>>;
+    ) -> impl Future<Output = Result<<UpdateCall<'_> as TwilioEndpoint>::ResponseBody>>;
 
     fn update_call_with_url(
         &self,
         call_sid: &str,
         url: &str,
-    ) -> impl Future<Output = Result<<UpdateCall as TwilioEndpoint>::ResponseBody>>;
+    ) -> impl Future<Output = Result<<UpdateCall<'_> as TwilioEndpoint>::ResponseBody>>;
+
+    /// Hangs up a call whatever its state, fetching it first to pick `canceled` for a
+    /// queued or ringing call and `completed` for one in progress. Errs with
+    /// [`TwilioError::CallNotLive`](crate::error::TwilioError::CallNotLive) if it already ended.
+    fn end_call(
+        &self,
+        call_sid: &str,
+    ) -> impl Future<Output = Result<<UpdateCall<'_> as TwilioEndpoint>::ResponseBody>>;
+
+    /// Plays `digits` as DTMF tones on a live call by redirecting it to `<Play digits>`.
+    ///
+    /// This replaces the TwiML the call was executing, so the call ends once the digits
+    /// have played unless it is redirected again.
+    fn send_digits(
+        &self,
+        call_sid: &str,
+        digits: &str,
+    ) -> impl Future<Output = Result<<UpdateCall<'_> as TwilioEndpoint>::ResponseBody>>;
+
+    /// Lists the conference's participants first and errs with
+    /// [`TwilioError::DuplicateLabel`](crate::error::TwilioError::DuplicateLabel)
+    /// if `label` is already taken, instead of letting the API reject the request.
+    fn create_unique_labeled_participant(
+        &self,
+        conference_sid: &str,
+        from: &str,
+        to: &str,
+        label: &str,
+    ) -> impl Future<Output = Result<<CreateParticipant<'_> as TwilioEndpoint>::ResponseBody>>;
+
+    /// Adds a participant and polls it until its leg connects, returning it then or once
+    /// it has failed or completed instead. Errs with
+    /// [`TwilioError::ParticipantTimeout`] if it's still queued or ringing after `timeout`.
+    fn create_participant_and_wait(
+        &self,
+        conference_sid: &str,
+        from: &str,
+        to: &str,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<ParticipantResponse>>;
+
+    /// Fetches the participant's call leg to find its direction, which participant
+    /// responses don't carry.
+    fn participant_direction(
+        &self,
+        participant: &ParticipantResponse,
+    ) -> impl Future<Output = Result<Option<CallDirection>>>;
+
+    /// Downloads an MMS media file and its content type, e.g. to store or forward it.
+    fn download_message_media(
+        &self,
+        message_sid: &str,
+        media_sid: &str,
+    ) -> impl Future<Output = Result<MediaContent>>;
 }
 
 impl TwilioClientExt for TwilioClient {
@@ -39,14 +143,8 @@ impl TwilioClientExt for TwilioClient {
         to: &str,
         from: &str,
         twiml: &str,
-    ) -> Result<<CreateCall as TwilioEndpoint>::ResponseBody> {
-        let body = CreateCallBody {
-            to,
-            from,
-            twiml: Some(twiml),
-            ..Default::default()
-        };
-        let endpoint = CreateCall::new(self.account_sid(), body);
+    ) -> Result<<CreateCall<'_> as TwilioEndpoint>::ResponseBody> {
+        let endpoint = CreateCall::with_twiml(self.account_sid(), to, from, twiml)?;
         self.hit(endpoint).await
     }
 
@@ -55,17 +153,74 @@ impl TwilioClientExt for TwilioClient {
         to: &str,
         from: &str,
         url: &str,
-    ) -> Result<<CreateCall as TwilioEndpoint>::ResponseBody> {
-        let body = CreateCallBody::new(to, from, url);
-        let endpoint = CreateCall::new(self.account_sid(), body);
+    ) -> Result<<CreateCall<'_> as TwilioEndpoint>::ResponseBody> {
+        let endpoint = CreateCall::with_url(self.account_sid(), to, from, url);
         self.hit(endpoint).await
     }
 
+    async fn create_calls(
+        &self,
+        recipients: &[&str],
+        from: &str,
+        url: &str,
+    ) -> Vec<Result<CallResponse>> {
+        let tasks = recipients.iter().map(|to| {
+            let client = self.clone();
+            let (to, from, url) = (to.to_string(), from.to_string(), url.to_string());
+            async move {
+                let body = CreateCallBody::new(&to, &from, &url);
+                let endpoint = CreateCall::new(client.account_sid(), body);
+                client.hit(endpoint).await
+            }
+        });
+        join_bounded(tasks, CREATE_CALLS_CONCURRENCY).await
+    }
+
+    async fn create_participants_in_regions(
+        &self,
+        conference_sid: &str,
+        from: &str,
+        targets: &[(&str, TwilioRegion)],
+    ) -> Vec<Result<ParticipantResponse>> {
+        let tasks = targets.iter().map(|(to, region)| {
+            let client = self.clone();
+            let (conference_sid, from, to) =
+                (conference_sid.to_string(), from.to_string(), to.to_string());
+            let region = region.clone();
+            async move {
+                let body = CreateParticipantBody {
+                    region: Some(region),
+                    ..CreateParticipantBody::new(&from, &to)
+                };
+                let endpoint = CreateParticipant::new(client.account_sid(), &conference_sid, body);
+                client.hit(endpoint).await
+            }
+        });
+        join_bounded(tasks, CREATE_CALLS_CONCURRENCY).await
+    }
+
+    async fn lookup_phone_numbers(
+        &self,
+        phone_numbers: &[&str],
+        fields: &[LookupField],
+    ) -> Vec<Result<LookupResponse>> {
+        let tasks = phone_numbers.iter().map(|phone_number| {
+            let client = self.clone();
+            let query = match fields {
+                [] => TwilioQuery::new(),
+                fields => TwilioQuery::new().with_fields(fields),
+            };
+            let endpoint = LookupPhoneNumber::new(*phone_number, query);
+            async move { client.hit(endpoint).await }
+        });
+        join_bounded(tasks, LOOKUP_CONCURRENCY).await
+    }
+
     async fn update_call_with_twiml(
         &self,
         call_sid: &str,
         twiml: &str,
-    ) -> Result<<UpdateCall as TwilioEndpoint>::ResponseBody> {
+    ) -> Result<<UpdateCall<'_> as TwilioEndpoint>::ResponseBody> {
         let body = UpdateCallBody {
             twiml: Some(twiml),
             ..Default::default()
@@ -78,7 +233,7 @@ impl TwilioClientExt for TwilioClient {
         &self,
         call_sid: &str,
         url: &str,
-    ) -> Result<<UpdateCall as TwilioEndpoint>::ResponseBody> {
+    ) -> Result<<UpdateCall<'_> as TwilioEndpoint>::ResponseBody> {
         let body = UpdateCallBody {
             url: Some(url),
             ..Default::default()
@@ -86,4 +241,331 @@ impl TwilioClientExt for TwilioClient {
         let endpoint = UpdateCall::new(self.account_sid(), call_sid, body);
         self.hit(endpoint).await
     }
+
+    async fn end_call(
+        &self,
+        call_sid: &str,
+    ) -> Result<<UpdateCall<'_> as TwilioEndpoint>::ResponseBody> {
+        let call = self
+            .hit(FetchCall::new(self.account_sid(), call_sid))
+            .await?;
+        let status = match &call.status {
+            Some(current) => UpdateCallStatus::ending(current)?,
+            None => UpdateCallStatus::Completed,
+        };
+        let body = UpdateCallBody::status(status);
+        self.hit(UpdateCall::new(self.account_sid(), call_sid, body))
+            .await
+    }
+
+    async fn send_digits(
+        &self,
+        call_sid: &str,
+        digits: &str,
+    ) -> Result<<UpdateCall<'_> as TwilioEndpoint>::ResponseBody> {
+        let twiml = VoiceResponse::new()
+            .play(Play::digits(digits))
+            .to_string()?;
+        self.update_call_with_twiml(call_sid, &twiml).await
+    }
+
+    async fn create_unique_labeled_participant(
+        &self,
+        conference_sid: &str,
+        from: &str,
+        to: &str,
+        label: &str,
+    ) -> Result<<CreateParticipant<'_> as TwilioEndpoint>::ResponseBody> {
+        // a conference holds at most 250 participants, so a single page covers it
+        let query = TwilioQuery::new().with_page_size(250);
+        let endpoint = ListParticipants::new(self.account_sid(), conference_sid).with_query(query);
+        self.hit(endpoint).await?.ensure_unique_label(label)?;
+
+        let body = CreateParticipantBody {
+            label: Some(label),
+            ..CreateParticipantBody::new(from, to)
+        };
+        let endpoint = CreateParticipant::new(self.account_sid(), conference_sid, body);
+        self.hit(endpoint).await
+    }
+
+    async fn create_participant_and_wait(
+        &self,
+        conference_sid: &str,
+        from: &str,
+        to: &str,
+        timeout: Duration,
+    ) -> Result<ParticipantResponse> {
+        let body = CreateParticipantBody::new(from, to);
+        let endpoint = CreateParticipant::new(self.account_sid(), conference_sid, body);
+        let participant = self.hit(endpoint).await?;
+        wait_for_participant(self, participant, timeout, PARTICIPANT_POLL_INTERVAL).await
+    }
+
+    async fn participant_direction(
+        &self,
+        participant: &ParticipantResponse,
+    ) -> Result<Option<CallDirection>> {
+        let endpoint = FetchCall::new(&participant.account_sid, &participant.call_sid);
+        Ok(self.hit(endpoint).await?.direction)
+    }
+
+    async fn download_message_media(
+        &self,
+        message_sid: &str,
+        media_sid: &str,
+    ) -> Result<MediaContent> {
+        let endpoint = FetchMessageMediaContent::new(self.account_sid(), message_sid, media_sid);
+        self.hit(endpoint).await
+    }
+}
+
+async fn wait_for_participant(
+    client: &TwilioClient,
+    mut participant: ParticipantResponse,
+    timeout: Duration,
+    interval: Duration,
+) -> Result<ParticipantResponse> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(
+            ParticipantStatus::Connected | ParticipantStatus::Complete | ParticipantStatus::Failed,
+        ) = participant.status
+        {
+            return Ok(participant);
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(TwilioError::ParticipantTimeout {
+                call_sid: participant.call_sid,
+                timeout,
+            });
+        }
+        tokio::time::sleep(interval.min(deadline - now)).await;
+
+        let endpoint = FetchParticipant::new(
+            client.account_sid(),
+            &participant.conference_sid,
+            &participant.call_sid,
+        );
+        participant = client.hit(endpoint).await?;
+    }
+}
+
+/// Runs `tasks` with at most `limit` in flight, returning their outputs in order.
+async fn join_bounded<T, F>(tasks: impl IntoIterator<Item = F>, limit: usize) -> Vec<T>
+where
+    T: Send + 'static,
+    F: Future<Output = T> + Send + 'static,
+{
+    let mut set = JoinSet::new();
+    let mut results = Vec::new();
+
+    for (i, task) in tasks.into_iter().enumerate() {
+        if set.len() >= limit {
+            results.extend(set.join_next().await.map(join_result));
+        }
+        set.spawn(async move { (i, task.await) });
+    }
+
+    while let Some(result) = set.join_next().await {
+        results.push(join_result(result));
+    }
+
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn join_result<T>(result: std::result::Result<T, tokio::task::JoinError>) -> T {
+    result.unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TwilioError;
+    use crate::mock_server::{MockResponse, MockServer};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn lookup_phone_numbers_is_returning_results_in_order() {
+        let server = MockServer::start(|req| {
+            let number = req
+                .path
+                .trim_start_matches("/v2/PhoneNumbers/")
+                .split('?')
+                .next()
+                .unwrap()
+                .to_string();
+            let valid = number.len() > 5;
+            MockResponse::json(
+                200,
+                format!(r#"{{"phone_number": "{number}", "valid": {valid}}}"#),
+            )
+        });
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+
+        let got = client
+            .lookup_phone_numbers(&["+14159929960", "+1415"], &[LookupField::CallerName])
+            .await;
+
+        let valid: Vec<_> = got.into_iter().map(|r| r.unwrap().valid).collect();
+        assert_eq!(valid, vec![true, false]);
+        assert!(server
+            .requests()
+            .iter()
+            .all(|r| r.path.ends_with("?Fields=caller_name")));
+    }
+
+    fn participant(status: &str) -> MockResponse {
+        MockResponse::json(
+            200,
+            format!(
+                r#"{{"account_sid": "AC123", "conference_sid": "CF123", "call_sid": "CA123", "status": "{status}", "uri": "/uri"}}"#
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn wait_for_participant_is_polling_until_connected() {
+        let server = MockServer::sequence(vec![
+            participant("queued"),
+            participant("ringing"),
+            participant("connected"),
+        ]);
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+        let body = CreateParticipantBody::new("+15017122661", "+15558675310");
+        let created = client
+            .hit(CreateParticipant::new("AC123", "CF123", body))
+            .await
+            .unwrap();
+
+        let got = wait_for_participant(
+            &client,
+            created,
+            Duration::from_secs(5),
+            Duration::from_millis(10),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(got.status, Some(ParticipantStatus::Connected));
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths[1..],
+            [
+                "/2010-04-01/Accounts/AC123/Conferences/CF123/Participants/CA123.json",
+                "/2010-04-01/Accounts/AC123/Conferences/CF123/Participants/CA123.json",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn create_participant_and_wait_is_erring_on_timeout() {
+        let server = MockServer::sequence(vec![participant("queued"), participant("ringing")]);
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+
+        let got = client
+            .create_participant_and_wait(
+                "CF123",
+                "+15017122661",
+                "+15558675310",
+                Duration::from_millis(50),
+            )
+            .await;
+
+        assert!(matches!(
+            got,
+            Err(TwilioError::ParticipantTimeout { call_sid, .. }) if call_sid == "CA123"
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_calls_is_returning_results_in_recipient_order() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let (current, max) = (in_flight.clone(), max_in_flight.clone());
+
+        let server = MockServer::start(move |req| {
+            let now = current.fetch_add(1, Ordering::SeqCst) + 1;
+            max.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(20));
+            current.fetch_sub(1, Ordering::SeqCst);
+
+            let to = req
+                .body
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("To="))
+                .unwrap()
+                .replace("%2B", "+");
+            if to == "+15550000013" {
+                return MockResponse::json(
+                    400,
+                    r#"{"code": 21211, "message": "Invalid 'To' Phone Number", "more_info": "https://www.twilio.com/docs/errors/21211", "status": 400}"#,
+                );
+            }
+            MockResponse::json(
+                201,
+                format!(
+                    r#"{{"sid": "CA123", "account_sid": "AC123", "to": "{to}", "from": "+15017122661", "uri": "/uri"}}"#
+                ),
+            )
+        });
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+
+        let recipients: Vec<String> = (0..25).map(|i| format!("+155500000{i:02}")).collect();
+        let recipients: Vec<&str> = recipients.iter().map(String::as_str).collect();
+        let results = client
+            .create_calls(&recipients, "+15017122661", "https://example.com/twiml")
+            .await;
+
+        assert_eq!(results.len(), 25);
+        for (to, result) in recipients.iter().zip(&results) {
+            match result {
+                Err(TwilioError::Api { status, .. }) => {
+                    assert_eq!(*to, "+15550000013");
+                    assert_eq!(status.as_u16(), 400);
+                }
+                Ok(call) => assert_eq!(call.to, *to),
+                Err(e) => panic!("unexpected error: {e}"),
+            }
+        }
+        assert!(results[13].is_err());
+        assert_eq!(server.requests().len(), 25);
+        assert!(max_in_flight.load(Ordering::SeqCst) <= CREATE_CALLS_CONCURRENCY);
+    }
+
+    #[tokio::test]
+    async fn create_participants_in_regions_is_setting_each_region() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                201,
+                r#"{"account_sid": "AC123", "conference_sid": "CF123", "call_sid": "CA123", "uri": "/uri"}"#,
+            )
+        });
+        let client = TwilioClient::new("AC123", "token").with_base_url(server.url());
+
+        let targets = [
+            ("+15558675310", TwilioRegion::Us1),
+            ("+61255501234", TwilioRegion::Au1),
+            ("+353155501234", TwilioRegion::Ie1),
+        ];
+        let results = client
+            .create_participants_in_regions("CF123", "+15017122661", &targets)
+            .await;
+        assert!(results.iter().all(Result::is_ok));
+
+        let mut bodies: Vec<String> = server.requests().into_iter().map(|r| r.body).collect();
+        bodies.sort();
+        assert_eq!(
+            bodies,
+            vec![
+                "From=%2B15017122661&To=%2B15558675310&Region=us1",
+                "From=%2B15017122661&To=%2B353155501234&Region=ie1",
+                "From=%2B15017122661&To=%2B61255501234&Region=au1",
+            ]
+        );
+    }
 }
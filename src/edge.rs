@@ -0,0 +1,171 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use strum::Display;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task::JoinSet;
+use url::Url;
+
+/// How long an edge may take to answer before it's left out of the measurement.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A Twilio edge location, where requests enter Twilio's network before reaching the region
+/// that processes them.
+/// See [Edge Locations](https://www.twilio.com/docs/global-infrastructure/edge-locations)
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Edge {
+    Ashburn,
+    Dublin,
+    Frankfurt,
+    SaoPaulo,
+    Singapore,
+    Sydney,
+    Tokyo,
+    Umatilla,
+}
+
+impl Edge {
+    pub const ALL: [Edge; 8] = [
+        Edge::Ashburn,
+        Edge::Dublin,
+        Edge::Frankfurt,
+        Edge::SaoPaulo,
+        Edge::Singapore,
+        Edge::Sydney,
+        Edge::Tokyo,
+        Edge::Umatilla,
+    ];
+
+    /// The API's base URL through this edge, e.g. `https://api.dublin.us1.twilio.com`.
+    ///
+    /// Requests are still processed in the default `us1` region, so the account's usual
+    /// credentials work from any edge.
+    pub fn base_url(&self) -> Url {
+        Url::parse(&format!("https://api.{self}.us1.twilio.com")).expect("edge URLs are valid")
+    }
+}
+
+/// Picks the edge that answers fastest, measuring once and sharing the choice between clones.
+#[derive(Clone, Debug)]
+pub(crate) struct AutoEdge {
+    candidates: Vec<(Edge, Url)>,
+    state: Arc<Mutex<AutoEdgeState>>,
+    /// Held while the edges are probed, so only one measurement runs at a time.
+    measurement: Arc<AsyncMutex<()>>,
+}
+
+#[derive(Debug, Default)]
+enum AutoEdgeState {
+    #[default]
+    Unmeasured,
+    Measuring,
+    Chosen(Edge, Url),
+    Unreachable,
+}
+
+impl AutoEdge {
+    pub(crate) fn new() -> Self {
+        Self::with_candidates(Edge::ALL.map(|edge| (edge, edge.base_url())).to_vec())
+    }
+
+    pub(crate) fn with_candidates(candidates: Vec<(Edge, Url)>) -> Self {
+        Self {
+            candidates,
+            state: Arc::new(Mutex::new(AutoEdgeState::default())),
+            measurement: Arc::new(AsyncMutex::new(())),
+        }
+    }
+
+    pub(crate) fn chosen(&self) -> Option<(Edge, Url)> {
+        match &*self.state() {
+            AutoEdgeState::Chosen(edge, url) => Some((*edge, url.clone())),
+            _ => None,
+        }
+    }
+
+    /// Measures in the background unless a measurement has already been started, doing
+    /// nothing outside a Tokio runtime.
+    pub(crate) fn measure_in_background(&self, client: &reqwest::Client) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let Ok(measurement) = self.measurement.clone().try_lock_owned() else {
+            return;
+        };
+        {
+            let mut state = self.state();
+            if !matches!(*state, AutoEdgeState::Unmeasured) {
+                return;
+            }
+            *state = AutoEdgeState::Measuring;
+        }
+        let (auto_edge, client) = (self.clone(), client.clone());
+        runtime.spawn(async move {
+            let _measurement = measurement;
+            auto_edge.probe(&client).await
+        });
+    }
+
+    /// Measures the candidates and keeps the fastest, `None` if none answered within
+    /// [`PROBE_TIMEOUT`]. While another measurement is running this waits for it and
+    /// returns its choice instead of starting a second one.
+    pub(crate) async fn measure(&self, client: &reqwest::Client) -> Option<Edge> {
+        let _measurement = match self.measurement.try_lock() {
+            Ok(measurement) => measurement,
+            Err(_) => {
+                drop(self.measurement.lock().await);
+                return self.chosen().map(|(edge, _)| edge);
+            }
+        };
+        self.probe(client).await
+    }
+
+    /// Sends a request to every candidate at once, waits for them all and keeps the one
+    /// that answered soonest with any status.
+    async fn probe(&self, client: &reqwest::Client) -> Option<Edge> {
+        let mut probes = JoinSet::new();
+        for (edge, url) in self.candidates.clone() {
+            let request = client.get(url.clone()).timeout(PROBE_TIMEOUT);
+            probes.spawn(async move {
+                let start = Instant::now();
+                request.send().await.ok()?;
+                Some((start.elapsed(), edge, url))
+            });
+        }
+
+        let mut fastest: Option<(Duration, Edge, Url)> = None;
+        while let Some(probe) = probes.join_next().await {
+            if let Ok(Some(probe)) = probe {
+                if fastest
+                    .as_ref()
+                    .is_none_or(|(elapsed, ..)| probe.0 < *elapsed)
+                {
+                    fastest = Some(probe);
+                }
+            }
+        }
+
+        *self.state() = match fastest {
+            Some((_, edge, url)) => AutoEdgeState::Chosen(edge, url),
+            None => AutoEdgeState::Unreachable,
+        };
+        self.chosen().map(|(edge, _)| edge)
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, AutoEdgeState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edge_base_url_is_keeping_us1_region() {
+        assert_eq!(
+            Edge::SaoPaulo.base_url().as_str(),
+            "https://api.sao-paulo.us1.twilio.com/"
+        );
+    }
+}
@@ -0,0 +1,406 @@
+//! Address endpoints
+//! See [Address resource reference](https://www.twilio.com/docs/usage/api/address)
+use super::*;
+use crate::endpoints::phone_numbers::incoming::IncomingPhoneNumberResponse;
+use crate::url::query::AddressQueryMarker;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Address Properties](https://www.twilio.com/docs/usage/api/address#address-properties)
+pub struct AddressResponse {
+    /// The SID of the Account that is responsible for the Address resource.
+    pub account_sid: String,
+    /// The city in which the address is located.
+    pub city: Option<String>,
+    /// The name associated with the address.
+    pub customer_name: Option<String>,
+    /// The date and time in GMT that the resource was created specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT that the resource was last updated specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// The string that you assigned to describe the resource.
+    pub friendly_name: Option<String>,
+    /// The ISO country code of the address.
+    pub iso_country: Option<String>,
+    /// The postal code of the address.
+    pub postal_code: Option<String>,
+    /// The state or region of the address.
+    pub region: Option<String>,
+    /// The unique string that that we created to identify the Address resource.
+    pub sid: String,
+    /// The number and street address of the address.
+    pub street: Option<String>,
+    /// The additional number and street address of the address.
+    pub street_secondary: Option<String>,
+    /// The URI of the resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+    /// Whether emergency calling has been enabled on this number.
+    pub emergency_enabled: Option<bool>,
+    /// Whether the address has been validated to comply with local regulation.
+    pub validated: Option<bool>,
+    /// Whether the address has been verified to comply with regulation.
+    pub verified: Option<bool>,
+}
+
+#[derive(Debug)]
+pub struct CreateAddress<'a> {
+    pub account_sid: String,
+    pub body: RequestBody<CreateAddressBody<'a>>,
+}
+
+impl<'a> CreateAddress<'a> {
+    pub fn new(account_sid: impl Into<String>, body: CreateAddressBody<'a>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateAddressBody<'a> {
+    pub customer_name: &'a str,
+    pub street: &'a str,
+    pub city: &'a str,
+    pub region: &'a str,
+    pub postal_code: &'a str,
+    /// The ISO country code, e.g. `US`.
+    pub iso_country: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_secondary: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emergency_enabled: Option<bool>,
+    /// Whether Twilio may correct the address. Twilio defaults to `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_correct_address: Option<bool>,
+}
+
+impl<'a> CreateAddressBody<'a> {
+    pub fn new(
+        customer_name: &'a str,
+        street: &'a str,
+        city: &'a str,
+        region: &'a str,
+        postal_code: &'a str,
+        iso_country: &'a str,
+    ) -> Self {
+        Self {
+            customer_name,
+            street,
+            city,
+            region,
+            postal_code,
+            iso_country,
+            ..Default::default()
+        }
+    }
+}
+
+impl TwilioEndpoint for CreateAddress<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Addresses.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = AddressResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchAddress {
+    pub account_sid: String,
+    pub address_sid: String,
+}
+
+impl FetchAddress {
+    pub fn new(account_sid: impl Into<String>, address_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            address_sid: address_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchAddress {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Addresses/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = AddressResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.address_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+impl ByFriendlyName for ListAddresses {}
+impl AddressQueryMarker for ListAddresses {}
+
+#[derive(Clone, Debug)]
+pub struct ListAddresses {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListAddresses {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListAddresses {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Addresses.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListAddressesResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListAddressesResponse {
+    pub addresses: Vec<AddressResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListAddressesResponse, addresses, AddressResponse);
+
+#[derive(Debug)]
+pub struct UpdateAddress<'a> {
+    pub account_sid: String,
+    pub address_sid: String,
+    pub body: RequestBody<UpdateAddressBody<'a>>,
+}
+
+impl<'a> UpdateAddress<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        address_sid: impl Into<String>,
+        body: UpdateAddressBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            address_sid: address_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+/// The ISO country of an address can't be changed.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateAddressBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_secondary: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emergency_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_correct_address: Option<bool>,
+}
+
+impl TwilioEndpoint for UpdateAddress<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Addresses/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = AddressResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.address_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+/// Twilio refuses to delete an address that phone numbers still depend on.
+#[derive(Clone, Debug)]
+pub struct DeleteAddress {
+    pub account_sid: String,
+    pub address_sid: String,
+}
+
+impl DeleteAddress {
+    pub fn new(account_sid: impl Into<String>, address_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            address_sid: address_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteAddress {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Addresses/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.address_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+/// Lists the phone numbers that require this address.
+#[derive(Clone, Debug)]
+pub struct ListDependentPhoneNumbers {
+    pub account_sid: String,
+    pub address_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListDependentPhoneNumbers {
+    pub fn new(
+        account_sid: impl Into<String>,
+        address_sid: impl Into<String>,
+        query: TwilioQuery<Self>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            address_sid: address_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListDependentPhoneNumbers {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Addresses/{AddressSid}/DependentPhoneNumbers.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListDependentPhoneNumbersResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{AddressSid}", &self.address_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListDependentPhoneNumbersResponse {
+    pub dependent_phone_numbers: Vec<IncomingPhoneNumberResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(
+    ListDependentPhoneNumbersResponse,
+    dependent_phone_numbers,
+    IncomingPhoneNumberResponse
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn create_address_body_is_serializing_required_fields() {
+        let body =
+            CreateAddressBody::new("Jane Doe", "1 Main St", "Berlin", "Berlin", "10115", "DE");
+        let got = serde_urlencoded::to_string(&body).unwrap();
+
+        assert_eq!(
+            got,
+            "CustomerName=Jane+Doe&Street=1+Main+St&City=Berlin&Region=Berlin&PostalCode=10115&IsoCountry=DE"
+        );
+    }
+
+    #[test]
+    fn list_addresses_is_filtering_by_customer_and_country() {
+        let query = TwilioQuery::new()
+            .with_customer_name("Jane Doe")
+            .with_iso_country("DE");
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = ListAddresses::new("AC123", query).url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/Addresses.json?CustomerName=Jane+Doe&IsoCountry=DE"
+        );
+    }
+
+    #[test]
+    fn list_dependent_phone_numbers_response_is_paginated() {
+        let json = r#"{"dependent_phone_numbers": [{"account_sid": "AC123", "friendly_name": "3197004499318", "phone_number": "+3197004499318", "sid": "PN123", "capabilities": {"voice": false, "sms": true, "mms": false, "fax": false}, "uri": "/uri"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListDependentPhoneNumbersResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(
+            resp.dependent_phone_numbers[0].phone_number,
+            "+3197004499318"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Balance endpoint
+//! See [Balance resource reference](https://www.twilio.com/docs/usage/api/balance)
+use super::*;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Balance Properties](https://www.twilio.com/docs/usage/api/balance#balance-properties)
+pub struct BalanceResponse {
+    /// The unique SID identifier of the Account.
+    pub account_sid: String,
+    /// The balance of the Account, in units specified by `currency`, e.g. `0.50`.
+    pub balance: String,
+    /// The units of currency for the account balance, e.g. `USD`.
+    pub currency: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchBalance {
+    pub account_sid: String,
+}
+
+impl FetchBalance {
+    pub fn new(account_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchBalance {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Balance.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = BalanceResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn balance_response_is_deserializing() {
+        let json = r#"{"currency": "USD", "balance": "0.05", "account_sid": "AC123"}"#;
+        let resp: BalanceResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.balance, "0.05");
+        assert_eq!(resp.currency, "USD");
+    }
+}
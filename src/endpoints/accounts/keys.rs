@@ -0,0 +1,447 @@
+//! API Key and Signing Key endpoints
+//! See [API Keys reference](https://www.twilio.com/docs/iam/api-keys/key-resource-v2010)
+//! and [Signing Keys reference](https://www.twilio.com/docs/iam/api/signingkey-resource)
+use super::*;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Key Properties](https://www.twilio.com/docs/iam/api-keys/key-resource-v2010#key-properties)
+pub struct KeyResponse {
+    /// The unique string that identifies the Key resource.
+    pub sid: String,
+    /// The string that you assigned to describe the resource.
+    pub friendly_name: Option<String>,
+    /// The date and time in GMT that the resource was created specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT that the resource was last updated specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// The secret your application uses to sign Access Tokens and to authenticate to the REST API.
+    /// Only returned when the key is created; it cannot be retrieved again afterwards.
+    pub secret: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct CreateKey<'a> {
+    pub account_sid: String,
+    pub body: RequestBody<CreateKeyBody<'a>>,
+}
+
+impl<'a> CreateKey<'a> {
+    pub fn new(account_sid: impl Into<String>, body: CreateKeyBody<'a>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateKeyBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+}
+
+impl TwilioEndpoint for CreateKey<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Keys.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = KeyResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchKey {
+    pub account_sid: String,
+    pub key_sid: String,
+}
+
+impl FetchKey {
+    pub fn new(account_sid: impl Into<String>, key_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            key_sid: key_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchKey {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Keys/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = KeyResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.key_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListKeys {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListKeys {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListKeys {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Keys.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListKeysResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListKeysResponse {
+    pub keys: Vec<KeyResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListKeysResponse, keys, KeyResponse);
+
+#[derive(Debug)]
+pub struct UpdateKey<'a> {
+    pub account_sid: String,
+    pub key_sid: String,
+    pub body: RequestBody<UpdateKeyBody<'a>>,
+}
+pub type UpdateKeyBody<'a> = CreateKeyBody<'a>;
+
+impl<'a> UpdateKey<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        key_sid: impl Into<String>,
+        body: UpdateKeyBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            key_sid: key_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+impl TwilioEndpoint for UpdateKey<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Keys/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = KeyResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.key_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteKey {
+    pub account_sid: String,
+    pub key_sid: String,
+}
+
+impl DeleteKey {
+    pub fn new(account_sid: impl Into<String>, key_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            key_sid: key_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteKey {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Keys/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.key_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+/// Signing Keys have the same properties as API Keys.
+pub type SigningKeyResponse = KeyResponse;
+
+#[derive(Debug)]
+pub struct CreateSigningKey<'a> {
+    pub account_sid: String,
+    pub body: RequestBody<CreateSigningKeyBody<'a>>,
+}
+pub type CreateSigningKeyBody<'a> = CreateKeyBody<'a>;
+
+impl<'a> CreateSigningKey<'a> {
+    pub fn new(account_sid: impl Into<String>, body: CreateSigningKeyBody<'a>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+impl TwilioEndpoint for CreateSigningKey<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SigningKeys.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = SigningKeyResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchSigningKey {
+    pub account_sid: String,
+    pub signing_key_sid: String,
+}
+
+impl FetchSigningKey {
+    pub fn new(account_sid: impl Into<String>, signing_key_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            signing_key_sid: signing_key_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchSigningKey {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SigningKeys/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = SigningKeyResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.signing_key_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListSigningKeys {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListSigningKeys {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListSigningKeys {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SigningKeys.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListSigningKeysResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListSigningKeysResponse {
+    pub signing_keys: Vec<SigningKeyResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListSigningKeysResponse, signing_keys, SigningKeyResponse);
+
+#[derive(Debug)]
+pub struct UpdateSigningKey<'a> {
+    pub account_sid: String,
+    pub signing_key_sid: String,
+    pub body: RequestBody<UpdateSigningKeyBody<'a>>,
+}
+pub type UpdateSigningKeyBody<'a> = CreateKeyBody<'a>;
+
+impl<'a> UpdateSigningKey<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        signing_key_sid: impl Into<String>,
+        body: UpdateSigningKeyBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            signing_key_sid: signing_key_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+impl TwilioEndpoint for UpdateSigningKey<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SigningKeys/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = SigningKeyResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.signing_key_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteSigningKey {
+    pub account_sid: String,
+    pub signing_key_sid: String,
+}
+
+impl DeleteSigningKey {
+    pub fn new(account_sid: impl Into<String>, signing_key_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            signing_key_sid: signing_key_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteSigningKey {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SigningKeys/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.signing_key_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_response_is_deserializing_secret_on_create() {
+        let json = r#"{"sid": "SK123", "friendly_name": "rotation", "date_created": "Mon, 13 Jun 2016 22:50:08 +0000", "date_updated": "Mon, 13 Jun 2016 22:50:08 +0000", "secret": "foobar"}"#;
+        let key: KeyResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(key.sid, "SK123");
+        assert_eq!(key.secret.as_deref(), Some("foobar"));
+    }
+
+    #[test]
+    fn list_keys_response_is_deserializing_without_secret() {
+        let json = r#"{"keys": [{"sid": "SK123", "friendly_name": "rotation", "date_created": "Mon, 13 Jun 2016 22:50:08 +0000", "date_updated": "Mon, 13 Jun 2016 22:50:08 +0000"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListKeysResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert!(resp.keys[0].secret.is_none());
+    }
+
+    #[test]
+    fn list_signing_keys_response_is_deserializing() {
+        let json = r#"{"signing_keys": [{"sid": "SK456", "friendly_name": "jwt", "date_created": "Mon, 13 Jun 2016 22:50:08 +0000", "date_updated": "Mon, 13 Jun 2016 22:50:08 +0000"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListSigningKeysResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(resp.signing_keys[0].sid, "SK456");
+    }
+}
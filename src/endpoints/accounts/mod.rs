@@ -1,6 +1,11 @@
 //! Accounts endpoints
 //! See [Twilio Accounts API](https://www.twilio.com/docs/iam/api/account)
 #![allow(unused_imports)]
+pub mod addresses;
+pub mod balance;
+pub mod keys;
+pub mod notifications;
+
 use super::*;
 use crate::url::query::{AccountQueryMarker, ByFriendlyName, TwilioQuery};
 use reqwest::RequestBuilder;
@@ -30,6 +35,14 @@ pub struct AccountResponse {
     pub uri: String,
 }
 
+impl AccountResponse {
+    /// Whether this is a trial account. Trial accounts can only call and message verified
+    /// numbers, and play a trial notice before executing TwiML, until upgraded.
+    pub fn is_trial(&self) -> bool {
+        self.r#type == AccountType::Trial
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Display, Serialize)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
@@ -39,7 +52,7 @@ pub enum Status {
     Closed,
 }
 
-#[derive(Clone, Debug, Deserialize, Display)]
+#[derive(Clone, Debug, Deserialize, Display, PartialEq)]
 #[strum(serialize_all = "lowercase")]
 pub enum AccountType {
     Trial,
@@ -151,6 +164,8 @@ pub struct ListAccountsResponse {
     pub pagination: Pagination,
 }
 
+impl_paginated!(ListAccountsResponse, accounts, AccountResponse);
+
 #[derive(Debug)]
 pub struct UpdateAccount<'a> {
     pub account_sid: String,
@@ -194,3 +209,23 @@ impl TwilioEndpoint for UpdateAccount<'_> {
         Ok(resp.json().await?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account_json(r#type: &str) -> String {
+        format!(
+            r#"{{"auth_token": "token", "date_created": "Thu, 30 Jul 2015 20:00:00 +0000", "date_updated": "Thu, 30 Jul 2015 20:00:00 +0000", "friendly_name": "name", "owner_account_sid": "AC123", "sid": "AC123", "status": "active", "type": "{type}", "uri": "/2010-04-01/Accounts/AC123.json"}}"#
+        )
+    }
+
+    #[test]
+    fn account_response_is_trial_when_type_is_trial() {
+        let trial: AccountResponse = serde_json::from_str(&account_json("Trial")).unwrap();
+        let full: AccountResponse = serde_json::from_str(&account_json("Full")).unwrap();
+
+        assert!(trial.is_trial());
+        assert!(!full.is_trial());
+    }
+}
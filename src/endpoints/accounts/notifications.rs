@@ -0,0 +1,203 @@
+//! Notification endpoints
+//! See [Notification resource reference](https://www.twilio.com/docs/usage/api/notifications)
+use super::*;
+use crate::endpoints::applications::ApiVersion;
+use crate::url::query::NotificationQueryMarker;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Notification Properties](https://www.twilio.com/docs/usage/api/notifications#notification-properties)
+pub struct NotificationResponse {
+    /// The SID of the Account that created the Notification resource.
+    pub account_sid: String,
+    /// The API version used to generate the notification.
+    pub api_version: Option<ApiVersion>,
+    /// The SID of the Call the Notification resource is associated with.
+    pub call_sid: Option<String>,
+    /// The date and time in GMT that the resource was created specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT that the resource was last updated specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// A unique error code for the error condition, listed in the Twilio error dictionary.
+    pub error_code: Option<String>,
+    /// Whether the notification is an error or a warning.
+    pub log: Option<NotificationLog>,
+    /// The date the notification was actually generated in RFC 2822 format.
+    pub message_date: Option<String>,
+    /// The text of the notification.
+    pub message_text: Option<String>,
+    /// The URL for more information about the error condition.
+    pub more_info: Option<String>,
+    /// The HTTP method used to generate the notification.
+    pub request_method: Option<String>,
+    /// The URL of the resource that generated the notification.
+    pub request_url: Option<String>,
+    /// The HTTP GET or POST variables we sent to your server. Only included when fetching.
+    pub request_variables: Option<String>,
+    /// The HTTP body returned by your server. Only included when fetching.
+    pub response_body: Option<String>,
+    /// The HTTP headers returned by your server. Only included when fetching.
+    pub response_headers: Option<String>,
+    /// The unique string that that we created to identify the Notification resource.
+    pub sid: String,
+    /// The URI of the resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+}
+
+/// Twilio sends and filters the log level as a digit.
+#[derive(Clone, Debug, Deserialize, Display, PartialEq, Serialize)]
+pub enum NotificationLog {
+    #[serde(rename = "0")]
+    #[strum(serialize = "0")]
+    Error,
+    #[serde(rename = "1")]
+    #[strum(serialize = "1")]
+    Warning,
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchNotification {
+    pub account_sid: String,
+    pub notification_sid: String,
+}
+
+impl FetchNotification {
+    pub fn new(account_sid: impl Into<String>, notification_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            notification_sid: notification_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchNotification {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Notifications/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = NotificationResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.notification_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+impl NotificationQueryMarker for ListNotifications {}
+
+#[derive(Clone, Debug)]
+pub struct ListNotifications {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListNotifications {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListNotifications {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Notifications.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListNotificationsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListNotificationsResponse {
+    pub notifications: Vec<NotificationResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(
+    ListNotificationsResponse,
+    notifications,
+    NotificationResponse
+);
+
+#[derive(Clone, Debug)]
+pub struct DeleteNotification {
+    pub account_sid: String,
+    pub notification_sid: String,
+}
+
+impl DeleteNotification {
+    pub fn new(account_sid: impl Into<String>, notification_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            notification_sid: notification_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteNotification {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Notifications/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.notification_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn list_notifications_response_is_deserializing_log_level() {
+        let json = r#"{"notifications": [{"account_sid": "AC123", "api_version": "2010-04-01", "call_sid": "CA123", "date_created": "Thu, 30 Apr 2015 16:47:33 +0000", "date_updated": "Thu, 30 Apr 2015 16:47:35 +0000", "error_code": "15003", "log": "1", "message_date": "Thu, 30 Apr 2015 16:47:32 +0000", "message_text": "LogLevel=WARN&Msg=Got+HTTP+404+response+to+https%3A%2F%2Fexample.com", "more_info": "https://www.twilio.com/docs/errors/15003", "request_method": "POST", "request_url": "https://example.com", "sid": "NO123", "uri": "/uri"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListNotificationsResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(resp.notifications[0].log, Some(NotificationLog::Warning));
+        assert_eq!(resp.notifications[0].error_code.as_deref(), Some("15003"));
+    }
+
+    #[test]
+    fn list_notifications_is_querying_log_and_message_date() {
+        let query = TwilioQuery::new()
+            .with_log(NotificationLog::Error)
+            .with_message_date_after("2024-01-01");
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = ListNotifications::new("AC123", query).url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/Notifications.json?Log=0&MessageDate%3E=2024-01-01"
+        );
+    }
+}
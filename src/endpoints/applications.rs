@@ -217,6 +217,8 @@ pub struct ListApplicationsResponse {
     pub pagination: Pagination,
 }
 
+impl_paginated!(ListApplicationsResponse, applications, ApplicationResponse);
+
 #[derive(Debug)]
 pub struct UpdateApplication<'a> {
     pub account_sid: String,
@@ -0,0 +1,158 @@
+//! Lookup endpoints
+//! See [Lookup v2 API](https://www.twilio.com/docs/lookup/v2-api)
+use super::*;
+use crate::url::query::{LookupQueryMarker, TwilioQuery};
+use strum::Display;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Lookup Response Properties](https://www.twilio.com/docs/lookup/v2-api#response-properties)
+pub struct LookupResponse {
+    /// The phone number in E.164 format, only set when it's `valid`.
+    pub phone_number: Option<String>,
+    /// Whether the phone number is a valid, dialable number.
+    pub valid: bool,
+    /// Why the number isn't valid, e.g. `TOO_SHORT` or `INVALID_COUNTRY_CODE`.
+    pub validation_errors: Option<Vec<String>>,
+    /// The phone number in the number's country's national format.
+    pub national_format: Option<String>,
+    /// The ISO 3166-1 alpha-2 country code for the phone number, e.g. `US`.
+    pub country_code: Option<String>,
+    /// The international dialing prefix of the phone number, e.g. `1`.
+    pub calling_country_code: Option<String>,
+    /// Only with [`LookupField::CallerName`].
+    pub caller_name: Option<CallerName>,
+    /// The carrier and line type, only with [`LookupField::LineTypeIntelligence`].
+    pub line_type_intelligence: Option<LineTypeIntelligence>,
+    /// The absolute URL of the resource.
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CallerName {
+    pub caller_name: Option<String>,
+    /// `BUSINESS` or `CONSUMER`.
+    pub caller_type: Option<String>,
+    pub error_code: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LineTypeIntelligence {
+    pub carrier_name: Option<String>,
+    /// e.g. `mobile`, `landline`, `fixedVoip` or `nonFixedVoip`.
+    pub r#type: Option<String>,
+    pub mobile_country_code: Option<String>,
+    pub mobile_network_code: Option<String>,
+    pub error_code: Option<u32>,
+}
+
+/// The data packages a lookup can add on top of the free validation and formatting.
+///
+/// These replace v1's `Type=carrier` and `Type=caller-name`.
+#[derive(Clone, Debug, Display, PartialEq)]
+#[strum(serialize_all = "snake_case")]
+pub enum LookupField {
+    CallerName,
+    LineTypeIntelligence,
+}
+
+impl LookupQueryMarker for LookupPhoneNumber {}
+
+/// Validates and formats `phone_number`, which may also be in national format when a
+/// `CountryCode` is given.
+#[derive(Clone, Debug)]
+pub struct LookupPhoneNumber {
+    pub phone_number: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl LookupPhoneNumber {
+    pub fn new(phone_number: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            phone_number: phone_number.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for LookupPhoneNumber {
+    const PATH: &'static str = "/v2/PhoneNumbers/{PhoneNumber}";
+
+    const METHOD: Method = Method::GET;
+
+    const SUBDOMAIN: &'static str = "lookups";
+
+    type ResponseBody = LookupResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{PhoneNumber}", &self.phone_number)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_phone_number_is_targeting_lookups_host() {
+        let query = TwilioQuery::new()
+            .with_fields(&[LookupField::CallerName, LookupField::LineTypeIntelligence]);
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = LookupPhoneNumber::new("+14159929960", query).url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://lookups.twilio.com/v2/PhoneNumbers/+14159929960?Fields=caller_name%2Cline_type_intelligence"
+        );
+    }
+
+    #[test]
+    fn lookup_phone_number_is_encoding_national_format_as_one_segment() {
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let query = TwilioQuery::new().with_country_code("DE");
+        let url = LookupPhoneNumber::new("030/1234567", query).url(&base_url);
+
+        assert_eq!(url.path(), "/v2/PhoneNumbers/030%2F1234567");
+
+        let url = LookupPhoneNumber::new("030 1234567", TwilioQuery::new()).url(&base_url);
+        assert_eq!(url.path(), "/v2/PhoneNumbers/030%201234567");
+    }
+
+    #[test]
+    fn lookup_phone_number_is_keeping_non_twilio_base_url() {
+        let base_url = Url::parse("http://127.0.0.1:8080").unwrap();
+        let url = LookupPhoneNumber::new("+14159929960", TwilioQuery::new()).url(&base_url);
+
+        assert_eq!(url.host_str(), Some("127.0.0.1"));
+    }
+
+    #[test]
+    fn lookup_response_is_deserializing() {
+        let json = r#"{"calling_country_code": "1", "country_code": "US", "phone_number": "+14159929960", "national_format": "(415) 992-9960", "valid": true, "validation_errors": [], "caller_name": {"caller_name": "TWILIO INC", "caller_type": "BUSINESS", "error_code": null}, "line_type_intelligence": {"carrier_name": "Twilio - SMS/MMS-SVR", "error_code": null, "mobile_country_code": "311", "mobile_network_code": "950", "type": "nonFixedVoip"}, "url": "https://lookups.twilio.com/v2/PhoneNumbers/+14159929960"}"#;
+        let lookup: LookupResponse = serde_json::from_str(json).unwrap();
+
+        assert!(lookup.valid);
+        assert_eq!(lookup.national_format.as_deref(), Some("(415) 992-9960"));
+        assert_eq!(lookup.country_code.as_deref(), Some("US"));
+        assert_eq!(
+            lookup.caller_name.unwrap().caller_type.as_deref(),
+            Some("BUSINESS")
+        );
+        assert_eq!(
+            lookup.line_type_intelligence.unwrap().r#type.as_deref(),
+            Some("nonFixedVoip")
+        );
+
+        let json = r#"{"calling_country_code": null, "country_code": null, "phone_number": "+1415", "national_format": null, "valid": false, "validation_errors": ["TOO_SHORT"], "caller_name": null, "line_type_intelligence": null, "url": "/url"}"#;
+        let lookup: LookupResponse = serde_json::from_str(json).unwrap();
+        assert!(!lookup.valid);
+        assert_eq!(lookup.validation_errors.unwrap(), ["TOO_SHORT"]);
+    }
+}
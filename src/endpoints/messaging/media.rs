@@ -0,0 +1,302 @@
+//! Message Media endpoints
+//! See [Media resource reference](https://www.twilio.com/docs/messaging/api/media-resource)
+use super::*;
+use crate::url::query::TwilioQuery;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Media Properties](https://www.twilio.com/docs/messaging/api/media-resource#media-properties)
+pub struct MediaResponse {
+    /// The SID of the Account associated with this Media resource.
+    pub account_sid: Option<String>,
+    /// The default MIME type of the media, e.g. `image/jpeg`, `image/png`, or `image/gif`.
+    pub content_type: Option<String>,
+    /// The date and time in GMT when this Media resource was created, specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT when this Media resource was last updated, specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// The SID of the Message resource that is associated with this Media resource.
+    pub parent_sid: Option<String>,
+    /// The unique string that identifies this Media resource.
+    pub sid: String,
+    /// The URI of this Media resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchMessageMedia {
+    pub account_sid: String,
+    pub message_sid: String,
+    pub media_sid: String,
+}
+
+impl FetchMessageMedia {
+    pub fn new(
+        account_sid: impl Into<String>,
+        message_sid: impl Into<String>,
+        media_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            message_sid: message_sid.into(),
+            media_sid: media_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchMessageMedia {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Messages/{MessageSid}/Media/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = MediaResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{MessageSid}", &self.message_sid),
+            ("{Sid}", &self.media_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+/// Downloads the media file itself rather than its metadata.
+///
+/// The media URI without the `.json` extension serves the file in its `content_type`,
+/// which is returned alongside the bytes in [`MediaContent`].
+#[derive(Clone, Debug)]
+pub struct FetchMessageMediaContent {
+    pub account_sid: String,
+    pub message_sid: String,
+    pub media_sid: String,
+}
+
+impl FetchMessageMediaContent {
+    pub fn new(
+        account_sid: impl Into<String>,
+        message_sid: impl Into<String>,
+        media_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            message_sid: message_sid.into(),
+            media_sid: media_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchMessageMediaContent {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Messages/{MessageSid}/Media/{Sid}";
+
+    const METHOD: Method = Method::GET;
+
+    const ACCEPT: &'static str = "*/*";
+
+    type ResponseBody = MediaContent;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{MessageSid}", &self.message_sid),
+            ("{Sid}", &self.media_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        let content_type = resp
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.split(';').next().unwrap_or(value).trim().to_string())
+            .filter(|value| !value.is_empty() && value != "application/octet-stream");
+        let data = resp.bytes().await?.to_vec();
+        let content_type = content_type.or_else(|| sniff_content_type(&data).map(String::from));
+
+        Ok(MediaContent { content_type, data })
+    }
+}
+
+/// A downloaded media file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MediaContent {
+    /// The MIME type of `data`, from the `Content-Type` header or, when that is missing or
+    /// generic, from the file's leading bytes.
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+/// Recognises the formats MMS attachments most often come in by their magic bytes.
+fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    match data {
+        [0xFF, 0xD8, 0xFF, ..] => Some("image/jpeg"),
+        [0x89, b'P', b'N', b'G', ..] => Some("image/png"),
+        [b'G', b'I', b'F', b'8', ..] => Some("image/gif"),
+        [b'%', b'P', b'D', b'F', ..] => Some("application/pdf"),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListMessageMedia {
+    pub account_sid: String,
+    pub message_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListMessageMedia {
+    pub fn new(
+        account_sid: impl Into<String>,
+        message_sid: impl Into<String>,
+        query: TwilioQuery<Self>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            message_sid: message_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListMessageMedia {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Messages/{MessageSid}/Media.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListMessageMediaResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{MessageSid}", &self.message_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListMessageMediaResponse {
+    pub media_list: Vec<MediaResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListMessageMediaResponse, media_list, MediaResponse);
+
+#[derive(Clone, Debug)]
+pub struct DeleteMessageMedia {
+    pub account_sid: String,
+    pub message_sid: String,
+    pub media_sid: String,
+}
+
+impl DeleteMessageMedia {
+    pub fn new(
+        account_sid: impl Into<String>,
+        message_sid: impl Into<String>,
+        media_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            message_sid: message_sid.into(),
+            media_sid: media_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteMessageMedia {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Messages/{MessageSid}/Media/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{MessageSid}", &self.message_sid),
+            ("{Sid}", &self.media_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn media_response_is_deserializing() {
+        let json = r#"{"account_sid": "AC123", "content_type": "image/jpeg", "date_created": "Sun, 16 Aug 2015 15:53:54 +0000", "date_updated": "Sun, 16 Aug 2015 15:53:55 +0000", "parent_sid": "MM123", "sid": "ME123", "uri": "/2010-04-01/Accounts/AC123/Messages/MM123/Media/ME123.json"}"#;
+        let media: MediaResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(media.sid, "ME123");
+        assert_eq!(media.content_type.as_deref(), Some("image/jpeg"));
+        assert_eq!(
+            media.uri,
+            "/2010-04-01/Accounts/AC123/Messages/MM123/Media/ME123.json"
+        );
+    }
+
+    #[test]
+    fn list_message_media_response_is_deserializing() {
+        let json = r#"{"media_list": [{"content_type": "image/png", "sid": "ME123", "uri": "/uri"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListMessageMediaResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(
+            resp.media_list[0].content_type.as_deref(),
+            Some("image/png")
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_message_media_content_is_detecting_content_type() {
+        let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        let resp = http::Response::builder()
+            .header("Content-Type", "application/octet-stream")
+            .body(png.to_vec())
+            .unwrap();
+        let got = FetchMessageMediaContent::response_body(resp.into())
+            .await
+            .unwrap();
+        assert_eq!(got.content_type.as_deref(), Some("image/png"));
+        assert_eq!(got.data, png);
+
+        let resp = http::Response::builder()
+            .header("Content-Type", "image/jpeg; charset=binary")
+            .body(b"media".to_vec())
+            .unwrap();
+        let got = FetchMessageMediaContent::response_body(resp.into())
+            .await
+            .unwrap();
+        assert_eq!(got.content_type.as_deref(), Some("image/jpeg"));
+    }
+
+    #[test]
+    fn fetch_message_media_content_is_dropping_json_extension() {
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = FetchMessageMediaContent::new("AC123", "MM123", "ME123").url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/Messages/MM123/Media/ME123"
+        );
+    }
+}
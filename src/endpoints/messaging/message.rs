@@ -0,0 +1,440 @@
+//! Message endpoints
+//! See [Message resource reference](https://www.twilio.com/docs/messaging/api/message-resource)
+use super::*;
+use crate::endpoints::applications::ApiVersion;
+use crate::url::query::{ByToAndFrom, MessageQueryMarker, TwilioQuery};
+use strum::Display;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Message Properties](https://www.twilio.com/docs/messaging/api/message-resource#message-properties)
+pub struct MessageResponse {
+    /// The SID of the Account associated with the Message resource.
+    pub account_sid: String,
+    /// The API version used to process the Message.
+    pub api_version: Option<ApiVersion>,
+    /// The text content of the message.
+    pub body: Option<String>,
+    /// The date and time in GMT that the resource was created specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT that the message was sent, specified in RFC 2822 format.
+    pub date_sent: Option<String>,
+    /// The date and time in GMT that the resource was last updated, specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// The direction of the message.
+    pub direction: Option<MessageDirection>,
+    /// The error code returned by your carrier if the message failed or is undelivered.
+    pub error_code: Option<i32>,
+    /// The description of the `error_code` if the message failed or is undelivered.
+    pub error_message: Option<String>,
+    /// The sender's phone number, alphanumeric sender ID, short code or channel address.
+    pub from: Option<String>,
+    /// The SID of the Messaging Service associated with the message.
+    pub messaging_service_sid: Option<String>,
+    /// The number of media files associated with the message.
+    pub num_media: Option<String>,
+    /// The number of segments that make up the complete message.
+    pub num_segments: Option<String>,
+    /// The amount billed for the message in the currency of `price_unit`.
+    pub price: Option<String>,
+    /// The currency of `price`, in ISO 4217 format (e.g. `USD`).
+    pub price_unit: Option<String>,
+    /// The unique string that identifies the Message resource.
+    pub sid: String,
+    /// The status of the message.
+    pub status: Option<MessageStatus>,
+    /// The URIs of the Media and Feedback subresources, relative to `https://api.twilio.com`.
+    pub subresource_uris: Option<serde_json::Value>,
+    /// The recipient's phone number or channel address.
+    pub to: String,
+    /// The URI of the Message resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Display, PartialEq, Serialize)]
+/// See [Message Status Values](https://www.twilio.com/docs/messaging/api/message-resource#message-status-values)
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MessageStatus {
+    Queued,
+    Sending,
+    Sent,
+    Failed,
+    Delivered,
+    Undelivered,
+    Receiving,
+    Received,
+    Accepted,
+    Scheduled,
+    Read,
+    PartiallyDelivered,
+    Canceled,
+    /// A status added by Twilio after this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Deserialize, Display, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum MessageDirection {
+    Inbound,
+    OutboundApi,
+    OutboundCall,
+    OutboundReply,
+    /// A direction added by Twilio after this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug)]
+pub struct CreateMessage<'a> {
+    pub account_sid: String,
+    pub body: RequestBody<CreateMessageBody<'a>>,
+}
+
+impl<'a> CreateMessage<'a> {
+    pub fn new(account_sid: impl Into<String>, body: CreateMessageBody<'a>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateMessageBody<'a> {
+    pub to: &'a str,
+    /// Required unless `messaging_service_sid` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messaging_service_sid: Option<&'a str>,
+    /// Required unless `media_url` or `content_sid` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<&'a str>,
+    /// Up to 10 publicly accessible media files to send as an MMS, one `MediaUrl` each.
+    #[serde(flatten, serialize_with = "serialize_media_urls")]
+    pub media_url: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_sid: Option<&'a str>,
+    /// JSON of the variables to substitute into the `content_sid` template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_variables: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_callback: Option<&'a str>,
+    /// Seconds the message may wait in the queue before it fails, between 1 and 36000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validity_period: Option<u32>,
+    /// Only `fixed`, with `send_at`, for sending through a Messaging Service later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_type: Option<&'a str>,
+    /// When to send a scheduled message, in ISO 8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_at: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shorten_urls: Option<bool>,
+}
+
+impl<'a> CreateMessageBody<'a> {
+    pub fn new(to: &'a str, from: &'a str, body: &'a str) -> Self {
+        Self {
+            to,
+            from: Some(from),
+            body: Some(body),
+            ..Default::default()
+        }
+    }
+}
+
+/// Repeats the `MediaUrl` key once per URL, which a plain field can't do.
+fn serialize_media_urls<S>(
+    media_urls: &&[&str],
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeMap;
+
+    let mut map = serializer.serialize_map(Some(media_urls.len()))?;
+    for media_url in media_urls.iter() {
+        map.serialize_entry("MediaUrl", media_url)?;
+    }
+    map.end()
+}
+
+impl TwilioEndpoint for CreateMessage<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Messages.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = MessageResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchMessage {
+    pub account_sid: String,
+    pub message_sid: String,
+}
+
+impl FetchMessage {
+    pub fn new(account_sid: impl Into<String>, message_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            message_sid: message_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchMessage {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Messages/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = MessageResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.message_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+impl ByToAndFrom for ListMessages {}
+impl MessageQueryMarker for ListMessages {}
+
+#[derive(Clone, Debug)]
+pub struct ListMessages {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListMessages {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListMessages {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Messages.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListMessagesResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListMessagesResponse {
+    pub messages: Vec<MessageResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListMessagesResponse, messages, MessageResponse);
+
+#[derive(Debug)]
+pub struct UpdateMessage<'a> {
+    pub account_sid: String,
+    pub message_sid: String,
+    pub body: RequestBody<UpdateMessageBody<'a>>,
+}
+
+impl<'a> UpdateMessage<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        message_sid: impl Into<String>,
+        body: UpdateMessageBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            message_sid: message_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateMessageBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<MessageStatus>,
+}
+
+impl UpdateMessageBody<'_> {
+    /// Blanks the message's body, which Twilio keeps otherwise.
+    pub fn redact() -> Self {
+        Self {
+            body: Some(""),
+            ..Default::default()
+        }
+    }
+
+    /// Cancels a message that is still `scheduled`.
+    pub fn cancel() -> Self {
+        Self {
+            status: Some(MessageStatus::Canceled),
+            ..Default::default()
+        }
+    }
+}
+
+impl TwilioEndpoint for UpdateMessage<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Messages/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = MessageResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.message_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteMessage {
+    pub account_sid: String,
+    pub message_sid: String,
+}
+
+impl DeleteMessage {
+    pub fn new(account_sid: impl Into<String>, message_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            message_sid: message_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteMessage {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Messages/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.message_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn message_response_is_deserializing() {
+        let json = r#"{"account_sid": "AC123", "api_version": "2010-04-01", "body": "Hi", "date_sent": "Thu, 24 Aug 2023 05:01:45 +0000", "direction": "outbound-api", "error_code": null, "from": "+14155552345", "num_media": "0", "num_segments": "1", "sid": "SM123", "status": "partially_delivered", "to": "+14155552346", "uri": "/2010-04-01/Accounts/AC123/Messages/SM123.json"}"#;
+        let message: MessageResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(message.sid, "SM123");
+        assert_eq!(message.status, Some(MessageStatus::PartiallyDelivered));
+        assert_eq!(message.direction, Some(MessageDirection::OutboundApi));
+    }
+
+    #[test]
+    fn message_direction_is_falling_back_to_unknown() {
+        let direction: MessageDirection = serde_json::from_str(r#""outbound-relay""#).unwrap();
+
+        assert_eq!(direction, MessageDirection::Unknown);
+    }
+
+    #[test]
+    fn create_message_body_is_repeating_media_url() {
+        let body = CreateMessageBody {
+            media_url: &["https://example.com/a.png", "https://example.com/b.png"],
+            ..CreateMessageBody::new("+14155552346", "+14155552345", "Hi")
+        };
+
+        assert_eq!(
+            serde_urlencoded::to_string(&body).unwrap(),
+            "To=%2B14155552346&From=%2B14155552345&Body=Hi&MediaUrl=https%3A%2F%2Fexample.com%2Fa.png&MediaUrl=https%3A%2F%2Fexample.com%2Fb.png"
+        );
+    }
+
+    #[test]
+    fn list_messages_is_filtering_by_to_from_and_date_sent() {
+        let query = TwilioQuery::new()
+            .with_to("+14155552346")
+            .with_from("+14155552345")
+            .with_date_sent_after("2023-08-01");
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = ListMessages::new("AC123", query).url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/Messages.json?To=%2B14155552346&From=%2B14155552345&DateSent%3E=2023-08-01"
+        );
+    }
+
+    #[test]
+    fn list_messages_response_is_paginated() {
+        let json = r#"{"messages": [{"account_sid": "AC123", "sid": "SM123", "to": "+14155552346", "uri": "/uri", "status": "delivered"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListMessagesResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(resp.messages[0].status, Some(MessageStatus::Delivered));
+    }
+
+    #[test]
+    fn update_message_body_cancel_is_sending_canceled_status() {
+        let got = serde_urlencoded::to_string(UpdateMessageBody::cancel()).unwrap();
+        assert_eq!(got, "Status=canceled");
+
+        let got = serde_urlencoded::to_string(UpdateMessageBody::redact()).unwrap();
+        assert_eq!(got, "Body=");
+    }
+}
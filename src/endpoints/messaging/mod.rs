@@ -0,0 +1,4 @@
+use super::*;
+pub mod media;
+pub mod message;
+pub mod short_codes;
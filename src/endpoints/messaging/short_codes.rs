@@ -0,0 +1,221 @@
+//! Short Code endpoints
+//! See [ShortCode resource reference](https://www.twilio.com/docs/sms/api/short-code)
+use super::*;
+use crate::endpoints::applications::ApiVersion;
+use crate::url::query::{ByFriendlyName, ShortCodeQueryMarker, TwilioQuery};
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [ShortCode Properties](https://www.twilio.com/docs/sms/api/short-code#shortcode-properties)
+pub struct ShortCodeResponse {
+    /// The SID of the Account that created this ShortCode resource.
+    pub account_sid: String,
+    /// The API version used to start a new TwiML session when an SMS message is sent to this short code.
+    pub api_version: Option<ApiVersion>,
+    /// The date and time in GMT that this resource was created specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT that this resource was last updated, specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// A string that you assigned to describe this resource. By default, the FriendlyName is the short code.
+    pub friendly_name: Option<String>,
+    /// The short code. e.g., 894546.
+    pub short_code: String,
+    /// The unique string that we created to identify this ShortCode resource.
+    pub sid: String,
+    /// The HTTP method we use to call the sms_fallback_url. Can be: GET or POST.
+    pub sms_fallback_method: Option<String>,
+    /// The URL that we call if an error occurs while retrieving or executing the TwiML from sms_url.
+    pub sms_fallback_url: Option<String>,
+    /// The HTTP method we use to call the sms_url. Can be: GET or POST.
+    pub sms_method: Option<String>,
+    /// The URL we call when receiving an incoming SMS message to this short code.
+    pub sms_url: Option<String>,
+    /// The URI of this resource, relative to https://api.twilio.com.
+    pub uri: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchShortCode {
+    pub account_sid: String,
+    pub short_code_sid: String,
+}
+
+impl FetchShortCode {
+    pub fn new(account_sid: impl Into<String>, short_code_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            short_code_sid: short_code_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchShortCode {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SMS/ShortCodes/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ShortCodeResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.short_code_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+impl ByFriendlyName for ListShortCodes {}
+impl ShortCodeQueryMarker for ListShortCodes {}
+
+#[derive(Clone, Debug)]
+pub struct ListShortCodes {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListShortCodes {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListShortCodes {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SMS/ShortCodes.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListShortCodesResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListShortCodesResponse {
+    pub short_codes: Vec<ShortCodeResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListShortCodesResponse, short_codes, ShortCodeResponse);
+
+#[derive(Debug)]
+pub struct UpdateShortCode<'a> {
+    pub account_sid: String,
+    pub short_code_sid: String,
+    pub body: RequestBody<UpdateShortCodeBody<'a>>,
+}
+
+impl<'a> UpdateShortCode<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        short_code_sid: impl Into<String>,
+        body: UpdateShortCodeBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            short_code_sid: short_code_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateShortCodeBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<ApiVersion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_fallback_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_fallback_method: Option<&'a str>,
+}
+
+impl TwilioEndpoint for UpdateShortCode<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SMS/ShortCodes/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = ShortCodeResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.short_code_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn list_short_codes_response_is_deserializing() {
+        let json = r#"{"short_codes": [{"account_sid": "AC123", "api_version": "2010-04-01", "date_created": null, "date_updated": null, "friendly_name": "API_CLUSTER_TEST_SHORT_CODE", "short_code": "99990", "sid": "SC123", "sms_fallback_method": "POST", "sms_fallback_url": null, "sms_method": "POST", "sms_url": null, "uri": "/2010-04-01/Accounts/AC123/SMS/ShortCodes/SC123.json"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListShortCodesResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(resp.short_codes[0].short_code, "99990");
+        assert_eq!(resp.short_codes[0].sms_method.as_deref(), Some("POST"));
+    }
+
+    #[test]
+    fn list_short_codes_is_querying_by_friendly_name_and_short_code() {
+        let query = TwilioQuery::new()
+            .with_friendly_name("alerts")
+            .with_short_code("8945");
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = ListShortCodes::new("AC123", query).url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/SMS/ShortCodes.json?FriendlyName=alerts&ShortCode=8945"
+        );
+    }
+
+    #[test]
+    fn update_short_code_body_is_serializing_sms_urls() {
+        let body = UpdateShortCodeBody {
+            sms_url: Some("https://example.com/sms"),
+            sms_method: Some("POST"),
+            sms_fallback_url: Some("https://example.com/fallback"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_urlencoded::to_string(&body).unwrap(),
+            "SmsUrl=https%3A%2F%2Fexample.com%2Fsms&SmsMethod=POST&SmsFallbackUrl=https%3A%2F%2Fexample.com%2Ffallback"
+        );
+    }
+}
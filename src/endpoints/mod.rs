@@ -1,18 +1,46 @@
 pub mod accounts;
 pub mod applications;
+pub mod lookups;
+pub mod messaging;
+pub mod phone_numbers;
+pub mod sip;
+pub mod usage;
 pub mod voice;
 
 pub use crate::Result;
 
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 use reqwest::{Method, RequestBuilder, Response, Url};
 pub use serde::{Deserialize, Serialize};
 
+/// What a path parameter must escape to stay one segment, e.g. the `/` in `030/1234567`.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
 #[allow(async_fn_in_trait)]
 pub trait TwilioEndpoint {
     const PATH: &'static str;
 
     const METHOD: Method;
 
+    /// The `Accept` header sent with the request, so proxies don't negotiate a format other
+    /// than the one `response_body` parses.
+    const ACCEPT: &'static str = "application/json";
+
+    /// The Twilio host the endpoint lives on, e.g. `lookups` for `lookups.twilio.com`. It
+    /// replaces the leading `api` of the client's base URL, so other base URLs are left alone.
+    const SUBDOMAIN: &'static str = "api";
+
     type ResponseBody;
 
     fn query_params(&self) -> Option<QueryValues> {
@@ -23,6 +51,12 @@ pub trait TwilioEndpoint {
         vec![]
     }
 
+    /// The endpoint's account SID, for [`TwilioClient::hit`](crate::TwilioClient::hit) to
+    /// fill in with the client's own when it's left empty, e.g. by [`voice::call::FetchCall::on`].
+    fn account_sid_mut(&mut self) -> Option<&mut String> {
+        None
+    }
+
     fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder>
     where
         Self: Sized,
@@ -34,10 +68,17 @@ pub trait TwilioEndpoint {
 
     fn url(&self, base_url: &Url) -> Url {
         let mut url = base_url.clone();
+        if Self::SUBDOMAIN != "api" {
+            if let Some(domain) = base_url.host_str().and_then(|h| h.strip_prefix("api.")) {
+                // a valid domain with its first label swapped is still valid
+                let _ = url.set_host(Some(&format!("{}.{domain}", Self::SUBDOMAIN)));
+            }
+        }
         let mut path = Self::PATH.to_string();
 
         for (placeholder, id) in self.path_params() {
-            path = path.replace(placeholder, id);
+            let id = utf8_percent_encode(id, PATH_SEGMENT).to_string();
+            path = path.replace(placeholder, &id);
         }
 
         url.set_path(&path);
@@ -79,3 +120,101 @@ pub struct Pagination {
     pub next_page_uri: Option<String>,
     pub previous_page_uri: Option<String>,
 }
+
+/// A page of a list resource. Each list endpoint nests its items under a different key
+/// (`calls`, `conferences`, `participants`, ...); this maps them to a common shape.
+pub trait Paginated {
+    type Item;
+
+    /// The key the items are nested under in the page's JSON.
+    const ITEMS_FIELD: &'static str;
+
+    fn items(&self) -> &[Self::Item];
+
+    fn into_items(self) -> Vec<Self::Item>;
+
+    fn pagination(&self) -> &Pagination;
+}
+
+macro_rules! impl_paginated {
+    ($response:ty, $field:ident, $item:ty) => {
+        impl $crate::endpoints::Paginated for $response {
+            type Item = $item;
+
+            const ITEMS_FIELD: &'static str = stringify!($field);
+
+            fn items(&self) -> &[Self::Item] {
+                &self.$field
+            }
+
+            fn into_items(self) -> Vec<Self::Item> {
+                self.$field
+            }
+
+            fn pagination(&self) -> &$crate::endpoints::Pagination {
+                &self.pagination
+            }
+        }
+    };
+}
+pub(crate) use impl_paginated;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::accounts::ListAccountsResponse;
+    use crate::endpoints::applications::ListApplicationsResponse;
+    use crate::endpoints::voice::call::ListCallsResponse;
+    use crate::endpoints::voice::conference::{ListConferencesResponse, ListParticipantsResponse};
+
+    fn page(key: &str, item: &str) -> String {
+        format!(
+            r#"{{"{key}": [{item}, {item}], "page": 1, "page_size": 2, "first_page_uri": "/first", "end": 3, "start": 2, "uri": "/uri", "next_page_uri": "/next", "previous_page_uri": "/prev"}}"#
+        )
+    }
+
+    fn assert_page<T: Paginated>(resp: T) {
+        assert_eq!(resp.items().len(), 2);
+        assert_eq!(resp.pagination().page, 1);
+        assert_eq!(resp.pagination().next_page_uri.as_deref(), Some("/next"));
+        assert_eq!(resp.into_items().len(), 2);
+    }
+
+    #[test]
+    fn list_accounts_response_is_paginated() {
+        let item = r#"{"auth_token": "token", "date_created": "now", "date_updated": "now", "friendly_name": "name", "owner_account_sid": "AC123", "sid": "AC123", "status": "active", "type": "Full", "uri": "/uri"}"#;
+        let resp: ListAccountsResponse = serde_json::from_str(&page("accounts", item)).unwrap();
+        assert_page(resp);
+    }
+
+    #[test]
+    fn list_applications_response_is_paginated() {
+        let item = r#"{"account_sid": "AC123", "api_version": "2010-04-01", "date_created": "now", "date_updated": "now", "sid": "AP123", "uri": "/uri"}"#;
+        let resp: ListApplicationsResponse =
+            serde_json::from_str(&page("applications", item)).unwrap();
+        assert_page(resp);
+    }
+
+    #[test]
+    fn list_calls_response_is_paginated() {
+        let item = r#"{"sid": "CA123", "account_sid": "AC123", "to": "+15558675310", "from": "+15017122661", "uri": "/uri"}"#;
+        let resp: ListCallsResponse = serde_json::from_str(&page("calls", item)).unwrap();
+        assert_page(resp);
+    }
+
+    #[test]
+    fn list_conferences_response_is_paginated() {
+        let item = r#"{"account_sid": "AC123", "api_version": "2010-04-01", "sid": "CF123", "status": "in-progress", "uri": "/uri"}"#;
+        let resp: ListConferencesResponse =
+            serde_json::from_str(&page("conferences", item)).unwrap();
+        assert_page(resp);
+    }
+
+    #[test]
+    fn list_participants_response_is_paginated() {
+        let item = r#"{"account_sid": "AC123", "conference_sid": "CF123", "call_sid": "CA123", "uri": "/uri"}"#;
+        let resp: ListParticipantsResponse =
+            serde_json::from_str(&page("participants", item)).unwrap();
+        assert_page(resp);
+    }
+}
@@ -0,0 +1,487 @@
+//! IncomingPhoneNumber endpoints
+//! See [IncomingPhoneNumber resource reference](https://www.twilio.com/docs/phone-numbers/api/incomingphonenumber-resource)
+use super::*;
+use crate::endpoints::applications::ApiVersion;
+use crate::url::query::{
+    ByFriendlyName, ByPhoneNumber, IncomingPhoneNumberQueryMarker, TwilioQuery,
+};
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [IncomingPhoneNumber Properties](https://www.twilio.com/docs/phone-numbers/api/incomingphonenumber-resource#incomingphonenumber-properties)
+pub struct IncomingPhoneNumberResponse {
+    /// The SID of the Account that created this IncomingPhoneNumber resource.
+    pub account_sid: String,
+    /// The API version used to start a new TwiML session.
+    pub api_version: Option<ApiVersion>,
+    /// Whether the phone number is new to the Twilio platform.
+    pub beta: Option<bool>,
+    /// The set of Boolean properties that indicate whether a phone number can receive calls or messages.
+    pub capabilities: Option<PhoneNumberCapabilities>,
+    /// The date and time in GMT that the resource was created specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT that the resource was last updated specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// The string that you assigned to describe the resource.
+    pub friendly_name: Option<String>,
+    /// The phone number's origin. `twilio` identifies Twilio-owned phone numbers and `hosted`
+    /// identifies hosted phone numbers.
+    pub origin: Option<String>,
+    /// The phone number in E.164 format.
+    pub phone_number: String,
+    /// The unique string that that we created to identify the resource.
+    pub sid: String,
+    /// The SID of the application that handles SMS messages sent to the phone number.
+    pub sms_application_sid: Option<String>,
+    /// The HTTP method we use to call sms_fallback_url. Can be: GET or POST.
+    pub sms_fallback_method: Option<String>,
+    /// The URL that we call when an error occurs while retrieving or executing the TwiML from sms_url.
+    pub sms_fallback_url: Option<String>,
+    /// The HTTP method we use to call sms_url. Can be: GET or POST.
+    pub sms_method: Option<String>,
+    /// The URL we call when the phone number receives an incoming SMS message.
+    pub sms_url: Option<String>,
+    /// The URL we call using the status_callback_method to send status information to your application.
+    pub status_callback: Option<String>,
+    /// The HTTP method we use to call status_callback. Can be: GET or POST.
+    pub status_callback_method: Option<String>,
+    /// The SID of the Trunk that handles calls to the phone number.
+    pub trunk_sid: Option<String>,
+    /// The URI of the resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+    /// The SID of the application that handles calls to the phone number.
+    pub voice_application_sid: Option<String>,
+    /// Whether we look up the caller's caller-ID name from the CNAM database.
+    pub voice_caller_id_lookup: Option<bool>,
+    /// The HTTP method we use to call voice_fallback_url. Can be: GET or POST.
+    pub voice_fallback_method: Option<String>,
+    /// The URL that we call when an error occurs retrieving or executing the TwiML requested by url.
+    pub voice_fallback_url: Option<String>,
+    /// The HTTP method we use to call voice_url. Can be: GET or POST.
+    pub voice_method: Option<String>,
+    /// The URL we call when the phone number receives a call.
+    pub voice_url: Option<String>,
+    /// The status of address registration with emergency services.
+    pub emergency_status: Option<String>,
+}
+
+/// What a phone number can be used for.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct PhoneNumberCapabilities {
+    pub voice: bool,
+    #[serde(alias = "SMS")]
+    pub sms: bool,
+    #[serde(alias = "MMS")]
+    pub mms: bool,
+    pub fax: bool,
+}
+
+/// The kinds of number the `IncomingPhoneNumbers` list can be narrowed to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NumberType {
+    Local,
+    Mobile,
+    TollFree,
+}
+
+impl NumberType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NumberType::Local => "Local",
+            NumberType::Mobile => "Mobile",
+            NumberType::TollFree => "TollFree",
+        }
+    }
+}
+
+impl ByFriendlyName for ListIncomingPhoneNumbers {}
+impl ByPhoneNumber for ListIncomingPhoneNumbers {}
+impl IncomingPhoneNumberQueryMarker for ListIncomingPhoneNumbers {}
+
+#[derive(Clone, Debug)]
+pub struct ListIncomingPhoneNumbers {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListIncomingPhoneNumbers {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListIncomingPhoneNumbers {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/IncomingPhoneNumbers.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListIncomingPhoneNumbersResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+impl ByFriendlyName for ListIncomingPhoneNumbersOfType {}
+impl ByPhoneNumber for ListIncomingPhoneNumbersOfType {}
+impl IncomingPhoneNumberQueryMarker for ListIncomingPhoneNumbersOfType {}
+
+/// Lists only the `Local`, `Mobile` or `TollFree` numbers of an account.
+#[derive(Clone, Debug)]
+pub struct ListIncomingPhoneNumbersOfType {
+    pub account_sid: String,
+    pub number_type: NumberType,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListIncomingPhoneNumbersOfType {
+    pub fn new(
+        account_sid: impl Into<String>,
+        number_type: NumberType,
+        query: TwilioQuery<Self>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            number_type,
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListIncomingPhoneNumbersOfType {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/IncomingPhoneNumbers/{Type}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListIncomingPhoneNumbersResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Type}", self.number_type.as_str()),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListIncomingPhoneNumbersResponse {
+    pub incoming_phone_numbers: Vec<IncomingPhoneNumberResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(
+    ListIncomingPhoneNumbersResponse,
+    incoming_phone_numbers,
+    IncomingPhoneNumberResponse
+);
+
+#[derive(Clone, Debug)]
+pub struct FetchIncomingPhoneNumber {
+    pub account_sid: String,
+    pub phone_number_sid: String,
+}
+
+impl FetchIncomingPhoneNumber {
+    pub fn new(account_sid: impl Into<String>, phone_number_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            phone_number_sid: phone_number_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchIncomingPhoneNumber {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/IncomingPhoneNumbers/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = IncomingPhoneNumberResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.phone_number_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+/// Provisions a new phone number on the account.
+#[derive(Debug)]
+pub struct CreateIncomingPhoneNumber<'a> {
+    pub account_sid: String,
+    pub body: RequestBody<CreateIncomingPhoneNumberBody<'a>>,
+}
+
+impl<'a> CreateIncomingPhoneNumber<'a> {
+    pub fn new(account_sid: impl Into<String>, body: CreateIncomingPhoneNumberBody<'a>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateIncomingPhoneNumberBody<'a> {
+    /// Required unless `area_code` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    /// Provisions any available number in this US or Canadian area code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_code: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_fallback_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_application_sid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_fallback_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_application_sid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_callback: Option<&'a str>,
+}
+
+impl<'a> CreateIncomingPhoneNumberBody<'a> {
+    /// Provisions exactly `phone_number`, in E.164 format.
+    pub fn new(phone_number: &'a str) -> Self {
+        Self {
+            phone_number: Some(phone_number),
+            ..Default::default()
+        }
+    }
+
+    /// Provisions any available number in `area_code`.
+    pub fn in_area_code(area_code: &'a str) -> Self {
+        Self {
+            area_code: Some(area_code),
+            ..Default::default()
+        }
+    }
+}
+
+impl TwilioEndpoint for CreateIncomingPhoneNumber<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/IncomingPhoneNumbers.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = IncomingPhoneNumberResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateIncomingPhoneNumber<'a> {
+    pub account_sid: String,
+    pub phone_number_sid: String,
+    pub body: RequestBody<UpdateIncomingPhoneNumberBody<'a>>,
+}
+
+impl<'a> UpdateIncomingPhoneNumber<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        phone_number_sid: impl Into<String>,
+        body: UpdateIncomingPhoneNumberBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            phone_number_sid: phone_number_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateIncomingPhoneNumberBody<'a> {
+    /// Moves the number to this account, which must be a subaccount or the parent account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_sid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_fallback_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_application_sid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_fallback_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_application_sid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_callback: Option<&'a str>,
+}
+
+impl TwilioEndpoint for UpdateIncomingPhoneNumber<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/IncomingPhoneNumbers/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = IncomingPhoneNumberResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.phone_number_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+/// Releases the number from the account.
+#[derive(Clone, Debug)]
+pub struct DeleteIncomingPhoneNumber {
+    pub account_sid: String,
+    pub phone_number_sid: String,
+}
+
+impl DeleteIncomingPhoneNumber {
+    pub fn new(account_sid: impl Into<String>, phone_number_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            phone_number_sid: phone_number_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteIncomingPhoneNumber {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/IncomingPhoneNumbers/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.phone_number_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn incoming_phone_number_response_is_deserializing_capabilities() {
+        let json = r#"{"account_sid": "AC123", "api_version": "2010-04-01", "beta": false, "capabilities": {"voice": true, "sms": true, "mms": false, "fax": false}, "friendly_name": "(808) 925-5327", "origin": "twilio", "phone_number": "+18089255327", "sid": "PN123", "uri": "/2010-04-01/Accounts/AC123/IncomingPhoneNumbers/PN123.json"}"#;
+        let number: IncomingPhoneNumberResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(number.sid, "PN123");
+        assert_eq!(
+            number.capabilities,
+            Some(PhoneNumberCapabilities {
+                voice: true,
+                sms: true,
+                mms: false,
+                fax: false,
+            })
+        );
+    }
+
+    #[test]
+    fn phone_number_capabilities_is_accepting_uppercase_keys() {
+        let json = r#"{"voice": true, "SMS": true, "MMS": true}"#;
+        let capabilities: PhoneNumberCapabilities = serde_json::from_str(json).unwrap();
+
+        assert!(capabilities.sms && capabilities.mms);
+        assert!(!capabilities.fax);
+    }
+
+    #[test]
+    fn list_incoming_phone_numbers_of_type_is_filtering_toll_free() {
+        let query = TwilioQuery::new()
+            .with_phone_number("+1800")
+            .with_friendly_name("support")
+            .with_beta(false);
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = ListIncomingPhoneNumbersOfType::new("AC123", NumberType::TollFree, query)
+            .url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/IncomingPhoneNumbers/TollFree.json?PhoneNumber=%2B1800&FriendlyName=support&Beta=false"
+        );
+    }
+
+    #[test]
+    fn list_incoming_phone_numbers_response_is_paginated() {
+        let json = r#"{"incoming_phone_numbers": [{"account_sid": "AC123", "phone_number": "+18089255327", "sid": "PN123", "uri": "/uri"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListIncomingPhoneNumbersResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(resp.incoming_phone_numbers[0].phone_number, "+18089255327");
+    }
+
+    #[test]
+    fn create_incoming_phone_number_body_is_provisioning_by_area_code() {
+        let got = serde_urlencoded::to_string(CreateIncomingPhoneNumberBody::in_area_code("415"))
+            .unwrap();
+
+        assert_eq!(got, "AreaCode=415");
+    }
+}
@@ -0,0 +1,3 @@
+use super::*;
+pub mod incoming;
+pub mod outgoing_caller_id;
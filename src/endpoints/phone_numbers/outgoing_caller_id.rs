@@ -0,0 +1,310 @@
+//! OutgoingCallerId and ValidationRequest endpoints
+//! See [OutgoingCallerIds resource reference](https://www.twilio.com/docs/voice/api/outgoing-caller-ids)
+use super::*;
+use crate::url::query::{ByFriendlyName, ByPhoneNumber, TwilioQuery};
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [OutgoingCallerId Properties](https://www.twilio.com/docs/voice/api/outgoing-caller-ids#outgoingcallerid-properties)
+pub struct OutgoingCallerIdResponse {
+    /// The SID of the Account that created the OutgoingCallerId resource.
+    pub account_sid: String,
+    /// The date and time in GMT that the resource was created specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT that the resource was last updated specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// The string that you assigned to describe the resource.
+    pub friendly_name: Option<String>,
+    /// The phone number in E.164 format.
+    pub phone_number: String,
+    /// The unique string that that we created to identify the OutgoingCallerId resource.
+    pub sid: String,
+    /// The URI of the resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchOutgoingCallerId {
+    pub account_sid: String,
+    pub caller_id_sid: String,
+}
+
+impl FetchOutgoingCallerId {
+    pub fn new(account_sid: impl Into<String>, caller_id_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            caller_id_sid: caller_id_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchOutgoingCallerId {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/OutgoingCallerIds/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = OutgoingCallerIdResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.caller_id_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+impl ByFriendlyName for ListOutgoingCallerIds {}
+impl ByPhoneNumber for ListOutgoingCallerIds {}
+
+#[derive(Clone, Debug)]
+pub struct ListOutgoingCallerIds {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListOutgoingCallerIds {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListOutgoingCallerIds {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/OutgoingCallerIds.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListOutgoingCallerIdsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListOutgoingCallerIdsResponse {
+    pub outgoing_caller_ids: Vec<OutgoingCallerIdResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(
+    ListOutgoingCallerIdsResponse,
+    outgoing_caller_ids,
+    OutgoingCallerIdResponse
+);
+
+#[derive(Debug)]
+pub struct UpdateOutgoingCallerId<'a> {
+    pub account_sid: String,
+    pub caller_id_sid: String,
+    pub body: RequestBody<UpdateOutgoingCallerIdBody<'a>>,
+}
+
+impl<'a> UpdateOutgoingCallerId<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        caller_id_sid: impl Into<String>,
+        body: UpdateOutgoingCallerIdBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            caller_id_sid: caller_id_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateOutgoingCallerIdBody<'a> {
+    /// Up to 64 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+}
+
+impl TwilioEndpoint for UpdateOutgoingCallerId<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/OutgoingCallerIds/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = OutgoingCallerIdResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.caller_id_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteOutgoingCallerId {
+    pub account_sid: String,
+    pub caller_id_sid: String,
+}
+
+impl DeleteOutgoingCallerId {
+    pub fn new(account_sid: impl Into<String>, caller_id_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            caller_id_sid: caller_id_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteOutgoingCallerId {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/OutgoingCallerIds/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.caller_id_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [ValidationRequest Properties](https://www.twilio.com/docs/voice/api/outgoing-caller-ids#validationrequest-properties)
+pub struct ValidationRequestResponse {
+    /// The SID of the Account responsible for the Caller ID.
+    pub account_sid: String,
+    /// The SID of the Call Twilio places to deliver the validation code.
+    pub call_sid: String,
+    /// The string that you assigned to describe the resource.
+    pub friendly_name: Option<String>,
+    /// The phone number to verify in E.164 format.
+    pub phone_number: String,
+    /// The 6 digit validation code that someone must enter to validate the Caller ID when
+    /// `phone_number` is called.
+    pub validation_code: String,
+}
+
+/// Starts verifying a caller ID: Twilio calls `phone_number`, and whoever answers must enter
+/// the returned `validation_code` for it to become an OutgoingCallerId.
+#[derive(Debug)]
+pub struct CreateValidationRequest<'a> {
+    pub account_sid: String,
+    pub body: RequestBody<CreateValidationRequestBody<'a>>,
+}
+
+impl<'a> CreateValidationRequest<'a> {
+    pub fn new(account_sid: impl Into<String>, body: CreateValidationRequestBody<'a>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateValidationRequestBody<'a> {
+    pub phone_number: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+    /// Seconds, between 0 and 60, to wait after the call connects before playing the code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_delay: Option<u8>,
+    /// Digits to dial after the call connects, e.g. to reach an extension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_callback: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_callback_method: Option<&'a str>,
+}
+
+impl<'a> CreateValidationRequestBody<'a> {
+    pub fn new(phone_number: &'a str) -> Self {
+        Self {
+            phone_number,
+            ..Default::default()
+        }
+    }
+}
+
+impl TwilioEndpoint for CreateValidationRequest<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/OutgoingCallerIds.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = ValidationRequestResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn validation_request_response_is_deserializing_code() {
+        let json = r#"{"account_sid": "AC123", "call_sid": "CA123", "friendly_name": "friendly_name", "phone_number": "+18001234567", "validation_code": "111111"}"#;
+        let resp: ValidationRequestResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.validation_code, "111111");
+        assert_eq!(resp.call_sid, "CA123");
+    }
+
+    #[test]
+    fn list_outgoing_caller_ids_is_filtering_by_phone_number() {
+        let query = TwilioQuery::new().with_phone_number("+14158675310");
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = ListOutgoingCallerIds::new("AC123", query).url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/OutgoingCallerIds.json?PhoneNumber=%2B14158675310"
+        );
+    }
+
+    #[test]
+    fn list_outgoing_caller_ids_response_is_paginated() {
+        let json = r#"{"outgoing_caller_ids": [{"account_sid": "AC123", "friendly_name": "(415) 867-5309", "phone_number": "+14158675309", "sid": "PN123", "uri": "/uri"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListOutgoingCallerIdsResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(resp.outgoing_caller_ids[0].phone_number, "+14158675309");
+    }
+}
@@ -0,0 +1,748 @@
+//! SIP CredentialList, Credential and CredentialListMapping endpoints
+//! See [SIP CredentialList resource reference](https://www.twilio.com/docs/voice/sip/api/sip-credentiallist-resource)
+use super::*;
+use crate::url::query::TwilioQuery;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [CredentialList Properties](https://www.twilio.com/docs/voice/sip/api/sip-credentiallist-resource#credentiallist-properties)
+pub struct CredentialListResponse {
+    /// The unique id of the Account that owns this resource.
+    pub account_sid: String,
+    /// The date that this resource was created, given as GMT in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date that this resource was last updated, given as GMT in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// A human readable descriptive text that describes the CredentialList, up to 64 characters long.
+    pub friendly_name: Option<String>,
+    /// A 34 character string that uniquely identifies this resource.
+    pub sid: String,
+    /// A list of credentials associated with this credential list.
+    pub subresource_uris: Option<serde_json::Value>,
+    /// The URI for this resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+}
+
+#[derive(Debug)]
+pub struct CreateCredentialList<'a> {
+    pub account_sid: String,
+    pub body: RequestBody<CreateCredentialListBody<'a>>,
+}
+
+impl<'a> CreateCredentialList<'a> {
+    pub fn new(account_sid: impl Into<String>, body: CreateCredentialListBody<'a>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateCredentialListBody<'a> {
+    /// Up to 64 characters.
+    pub friendly_name: &'a str,
+}
+pub type UpdateCredentialListBody<'a> = CreateCredentialListBody<'a>;
+
+impl TwilioEndpoint for CreateCredentialList<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SIP/CredentialLists.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = CredentialListResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchCredentialList {
+    pub account_sid: String,
+    pub credential_list_sid: String,
+}
+
+impl FetchCredentialList {
+    pub fn new(account_sid: impl Into<String>, credential_list_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            credential_list_sid: credential_list_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchCredentialList {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SIP/CredentialLists/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = CredentialListResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.credential_list_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListCredentialLists {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListCredentialLists {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListCredentialLists {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SIP/CredentialLists.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListCredentialListsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListCredentialListsResponse {
+    pub credential_lists: Vec<CredentialListResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(
+    ListCredentialListsResponse,
+    credential_lists,
+    CredentialListResponse
+);
+
+#[derive(Debug)]
+pub struct UpdateCredentialList<'a> {
+    pub account_sid: String,
+    pub credential_list_sid: String,
+    pub body: RequestBody<UpdateCredentialListBody<'a>>,
+}
+
+impl<'a> UpdateCredentialList<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        credential_list_sid: impl Into<String>,
+        body: UpdateCredentialListBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            credential_list_sid: credential_list_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+impl TwilioEndpoint for UpdateCredentialList<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SIP/CredentialLists/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = CredentialListResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.credential_list_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteCredentialList {
+    pub account_sid: String,
+    pub credential_list_sid: String,
+}
+
+impl DeleteCredentialList {
+    pub fn new(account_sid: impl Into<String>, credential_list_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            credential_list_sid: credential_list_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteCredentialList {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SIP/CredentialLists/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.credential_list_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Credential Properties](https://www.twilio.com/docs/voice/sip/api/sip-credential-resource#credential-properties)
+pub struct CredentialResponse {
+    /// The unique id of the Account that is responsible for this resource.
+    pub account_sid: String,
+    /// The unique id that identifies the credential list that includes this credential.
+    pub credential_list_sid: String,
+    /// The date that this resource was created, given as GMT in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date that this resource was last updated, given as GMT in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// A 34 character string that uniquely identifies this resource.
+    pub sid: String,
+    /// The URI for this resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+    /// The username for this credential.
+    pub username: String,
+}
+
+#[derive(Debug)]
+pub struct CreateCredential<'a> {
+    pub account_sid: String,
+    pub credential_list_sid: String,
+    pub body: RequestBody<CreateCredentialBody<'a>>,
+}
+
+impl<'a> CreateCredential<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        credential_list_sid: impl Into<String>,
+        body: CreateCredentialBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            credential_list_sid: credential_list_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateCredentialBody<'a> {
+    /// Unique within the list, up to 32 characters.
+    pub username: &'a str,
+    /// At least 12 characters with a digit, an uppercase and a lowercase letter.
+    pub password: &'a str,
+}
+
+impl TwilioEndpoint for CreateCredential<'_> {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/CredentialLists/{CredentialListSid}/Credentials.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = CredentialResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CredentialListSid}", &self.credential_list_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchCredential {
+    pub account_sid: String,
+    pub credential_list_sid: String,
+    pub credential_sid: String,
+}
+
+impl FetchCredential {
+    pub fn new(
+        account_sid: impl Into<String>,
+        credential_list_sid: impl Into<String>,
+        credential_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            credential_list_sid: credential_list_sid.into(),
+            credential_sid: credential_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchCredential {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/CredentialLists/{CredentialListSid}/Credentials/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = CredentialResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CredentialListSid}", &self.credential_list_sid),
+            ("{Sid}", &self.credential_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListCredentials {
+    pub account_sid: String,
+    pub credential_list_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListCredentials {
+    pub fn new(
+        account_sid: impl Into<String>,
+        credential_list_sid: impl Into<String>,
+        query: TwilioQuery<Self>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            credential_list_sid: credential_list_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListCredentials {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/CredentialLists/{CredentialListSid}/Credentials.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListCredentialsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CredentialListSid}", &self.credential_list_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListCredentialsResponse {
+    pub credentials: Vec<CredentialResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListCredentialsResponse, credentials, CredentialResponse);
+
+/// Changes a credential's password; its username can't be changed.
+#[derive(Debug)]
+pub struct UpdateCredential<'a> {
+    pub account_sid: String,
+    pub credential_list_sid: String,
+    pub credential_sid: String,
+    pub body: RequestBody<UpdateCredentialBody<'a>>,
+}
+
+impl<'a> UpdateCredential<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        credential_list_sid: impl Into<String>,
+        credential_sid: impl Into<String>,
+        body: UpdateCredentialBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            credential_list_sid: credential_list_sid.into(),
+            credential_sid: credential_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateCredentialBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<&'a str>,
+}
+
+impl TwilioEndpoint for UpdateCredential<'_> {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/CredentialLists/{CredentialListSid}/Credentials/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = CredentialResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CredentialListSid}", &self.credential_list_sid),
+            ("{Sid}", &self.credential_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteCredential {
+    pub account_sid: String,
+    pub credential_list_sid: String,
+    pub credential_sid: String,
+}
+
+impl DeleteCredential {
+    pub fn new(
+        account_sid: impl Into<String>,
+        credential_list_sid: impl Into<String>,
+        credential_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            credential_list_sid: credential_list_sid.into(),
+            credential_sid: credential_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteCredential {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/CredentialLists/{CredentialListSid}/Credentials/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CredentialListSid}", &self.credential_list_sid),
+            ("{Sid}", &self.credential_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [CredentialListMapping Properties](https://www.twilio.com/docs/voice/sip/api/sip-credentiallistmapping-resource#credentiallistmapping-properties)
+pub struct CredentialListMappingResponse {
+    /// The unique id of the Account that is responsible for this resource.
+    pub account_sid: String,
+    /// The date that this resource was created, given as GMT in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date that this resource was last updated, given as GMT in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// A human readable descriptive text for this resource, up to 64 characters long.
+    pub friendly_name: Option<String>,
+    /// The SID of the mapped CredentialList.
+    pub sid: String,
+    /// The URIs of the mapped CredentialList's subresources.
+    pub subresource_uris: Option<serde_json::Value>,
+    /// The URI for this resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+}
+
+/// Makes a SIP domain authenticate calls against a credential list.
+#[derive(Debug)]
+pub struct CreateCredentialListMapping<'a> {
+    pub account_sid: String,
+    pub domain_sid: String,
+    pub body: RequestBody<CreateCredentialListMappingBody<'a>>,
+}
+
+impl<'a> CreateCredentialListMapping<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        domain_sid: impl Into<String>,
+        body: CreateCredentialListMappingBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            domain_sid: domain_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateCredentialListMappingBody<'a> {
+    pub credential_list_sid: &'a str,
+}
+
+impl TwilioEndpoint for CreateCredentialListMapping<'_> {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/Domains/{DomainSid}/CredentialListMappings.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = CredentialListMappingResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{DomainSid}", &self.domain_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchCredentialListMapping {
+    pub account_sid: String,
+    pub domain_sid: String,
+    pub credential_list_sid: String,
+}
+
+impl FetchCredentialListMapping {
+    pub fn new(
+        account_sid: impl Into<String>,
+        domain_sid: impl Into<String>,
+        credential_list_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            domain_sid: domain_sid.into(),
+            credential_list_sid: credential_list_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchCredentialListMapping {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/Domains/{DomainSid}/CredentialListMappings/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = CredentialListMappingResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{DomainSid}", &self.domain_sid),
+            ("{Sid}", &self.credential_list_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListCredentialListMappings {
+    pub account_sid: String,
+    pub domain_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListCredentialListMappings {
+    pub fn new(
+        account_sid: impl Into<String>,
+        domain_sid: impl Into<String>,
+        query: TwilioQuery<Self>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            domain_sid: domain_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListCredentialListMappings {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/Domains/{DomainSid}/CredentialListMappings.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListCredentialListMappingsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{DomainSid}", &self.domain_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListCredentialListMappingsResponse {
+    pub credential_list_mappings: Vec<CredentialListMappingResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(
+    ListCredentialListMappingsResponse,
+    credential_list_mappings,
+    CredentialListMappingResponse
+);
+
+/// Stops a SIP domain from authenticating against a credential list.
+#[derive(Clone, Debug)]
+pub struct DeleteCredentialListMapping {
+    pub account_sid: String,
+    pub domain_sid: String,
+    pub credential_list_sid: String,
+}
+
+impl DeleteCredentialListMapping {
+    pub fn new(
+        account_sid: impl Into<String>,
+        domain_sid: impl Into<String>,
+        credential_list_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            domain_sid: domain_sid.into(),
+            credential_list_sid: credential_list_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteCredentialListMapping {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/Domains/{DomainSid}/CredentialListMappings/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{DomainSid}", &self.domain_sid),
+            ("{Sid}", &self.credential_list_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn list_credentials_response_is_deserializing_usernames() {
+        let json = r#"{"credentials": [{"account_sid": "AC123", "credential_list_sid": "CL123", "date_created": "Wed, 19 Aug 2015 19:48:45 +0000", "date_updated": "Wed, 19 Aug 2015 19:48:45 +0000", "sid": "CR123", "uri": "/uri", "username": "1440013725.28"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListCredentialsResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(resp.credentials[0].username, "1440013725.28");
+        assert_eq!(resp.credentials[0].credential_list_sid, "CL123");
+    }
+
+    #[test]
+    fn create_credential_body_is_serializing_username_and_password() {
+        let body = CreateCredentialBody {
+            username: "alice",
+            password: "Sup3rSecretPass",
+        };
+
+        assert_eq!(
+            serde_urlencoded::to_string(&body).unwrap(),
+            "Username=alice&Password=Sup3rSecretPass"
+        );
+    }
+
+    #[test]
+    fn delete_credential_list_mapping_is_scoping_path_to_domain() {
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = DeleteCredentialListMapping::new("AC123", "SD123", "CL123").url(&base_url);
+
+        assert_eq!(
+            url.path(),
+            "/2010-04-01/Accounts/AC123/SIP/Domains/SD123/CredentialListMappings/CL123.json"
+        );
+    }
+}
@@ -0,0 +1,780 @@
+//! SIP IpAccessControlList, IpAddress and IpAccessControlListMapping endpoints
+//! See [SIP IpAccessControlList resource reference](https://www.twilio.com/docs/voice/sip/api/sip-ipaccesscontrollist-resource)
+use super::*;
+use crate::url::query::TwilioQuery;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [IpAccessControlList Properties](https://www.twilio.com/docs/voice/sip/api/sip-ipaccesscontrollist-resource#ipaccesscontrollist-properties)
+pub struct IpAccessControlListResponse {
+    /// The unique id of the Account that owns this resource.
+    pub account_sid: String,
+    /// The date that this resource was created, given as GMT in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date that this resource was last updated, given as GMT in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// A human readable descriptive text, up to 255 characters long.
+    pub friendly_name: Option<String>,
+    /// A 34 character string that uniquely identifies this resource.
+    pub sid: String,
+    /// A list of the IpAddress resources associated with this IP access control list.
+    pub subresource_uris: Option<serde_json::Value>,
+    /// The URI for this resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+}
+
+#[derive(Debug)]
+pub struct CreateIpAccessControlList<'a> {
+    pub account_sid: String,
+    pub body: RequestBody<CreateIpAccessControlListBody<'a>>,
+}
+
+impl<'a> CreateIpAccessControlList<'a> {
+    pub fn new(account_sid: impl Into<String>, body: CreateIpAccessControlListBody<'a>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateIpAccessControlListBody<'a> {
+    /// Up to 255 characters.
+    pub friendly_name: &'a str,
+}
+pub type UpdateIpAccessControlListBody<'a> = CreateIpAccessControlListBody<'a>;
+
+impl TwilioEndpoint for CreateIpAccessControlList<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SIP/IpAccessControlLists.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = IpAccessControlListResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchIpAccessControlList {
+    pub account_sid: String,
+    pub ip_access_control_list_sid: String,
+}
+
+impl FetchIpAccessControlList {
+    pub fn new(
+        account_sid: impl Into<String>,
+        ip_access_control_list_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            ip_access_control_list_sid: ip_access_control_list_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchIpAccessControlList {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/IpAccessControlLists/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = IpAccessControlListResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.ip_access_control_list_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListIpAccessControlLists {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListIpAccessControlLists {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListIpAccessControlLists {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/SIP/IpAccessControlLists.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListIpAccessControlListsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListIpAccessControlListsResponse {
+    pub ip_access_control_lists: Vec<IpAccessControlListResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(
+    ListIpAccessControlListsResponse,
+    ip_access_control_lists,
+    IpAccessControlListResponse
+);
+
+#[derive(Debug)]
+pub struct UpdateIpAccessControlList<'a> {
+    pub account_sid: String,
+    pub ip_access_control_list_sid: String,
+    pub body: RequestBody<UpdateIpAccessControlListBody<'a>>,
+}
+
+impl<'a> UpdateIpAccessControlList<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        ip_access_control_list_sid: impl Into<String>,
+        body: UpdateIpAccessControlListBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            ip_access_control_list_sid: ip_access_control_list_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+impl TwilioEndpoint for UpdateIpAccessControlList<'_> {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/IpAccessControlLists/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = IpAccessControlListResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.ip_access_control_list_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteIpAccessControlList {
+    pub account_sid: String,
+    pub ip_access_control_list_sid: String,
+}
+
+impl DeleteIpAccessControlList {
+    pub fn new(
+        account_sid: impl Into<String>,
+        ip_access_control_list_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            ip_access_control_list_sid: ip_access_control_list_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteIpAccessControlList {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/IpAccessControlLists/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.ip_access_control_list_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [IpAddress Properties](https://www.twilio.com/docs/voice/sip/api/sip-ipaddress-resource#ipaddress-properties)
+pub struct IpAddressResponse {
+    /// The unique id of the Account that is responsible for this resource.
+    pub account_sid: String,
+    /// An integer representing the length of the CIDR prefix to use with this IP address.
+    pub cidr_prefix_length: Option<u8>,
+    /// The date that this resource was created, given as GMT in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date that this resource was last updated, given as GMT in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// A human readable descriptive text for this resource, up to 255 characters long.
+    pub friendly_name: Option<String>,
+    /// An IP address in dotted decimal notation from which you want to accept traffic.
+    pub ip_address: String,
+    /// The unique id of the IpAccessControlList resource that includes this resource.
+    pub ip_access_control_list_sid: String,
+    /// A 34 character string that uniquely identifies this resource.
+    pub sid: String,
+    /// The URI for this resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+}
+
+#[derive(Debug)]
+pub struct CreateIpAddress<'a> {
+    pub account_sid: String,
+    pub ip_access_control_list_sid: String,
+    pub body: RequestBody<CreateIpAddressBody<'a>>,
+}
+
+impl<'a> CreateIpAddress<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        ip_access_control_list_sid: impl Into<String>,
+        body: CreateIpAddressBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            ip_access_control_list_sid: ip_access_control_list_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateIpAddressBody<'a> {
+    /// Up to 255 characters.
+    pub friendly_name: &'a str,
+    /// An IPv4 address in dotted decimal notation; IPv6 is not supported.
+    pub ip_address: &'a str,
+    /// Accepts the whole block `ip_address/cidr_prefix_length`. Twilio defaults to 32.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cidr_prefix_length: Option<u8>,
+}
+
+impl<'a> CreateIpAddressBody<'a> {
+    pub fn new(friendly_name: &'a str, ip_address: &'a str) -> Self {
+        Self {
+            friendly_name,
+            ip_address,
+            cidr_prefix_length: None,
+        }
+    }
+}
+
+impl TwilioEndpoint for CreateIpAddress<'_> {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/IpAccessControlLists/{IpAccessControlListSid}/IpAddresses.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = IpAddressResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{IpAccessControlListSid}", &self.ip_access_control_list_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchIpAddress {
+    pub account_sid: String,
+    pub ip_access_control_list_sid: String,
+    pub ip_address_sid: String,
+}
+
+impl FetchIpAddress {
+    pub fn new(
+        account_sid: impl Into<String>,
+        ip_access_control_list_sid: impl Into<String>,
+        ip_address_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            ip_access_control_list_sid: ip_access_control_list_sid.into(),
+            ip_address_sid: ip_address_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchIpAddress {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/IpAccessControlLists/{IpAccessControlListSid}/IpAddresses/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = IpAddressResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{IpAccessControlListSid}", &self.ip_access_control_list_sid),
+            ("{Sid}", &self.ip_address_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListIpAddresses {
+    pub account_sid: String,
+    pub ip_access_control_list_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListIpAddresses {
+    pub fn new(
+        account_sid: impl Into<String>,
+        ip_access_control_list_sid: impl Into<String>,
+        query: TwilioQuery<Self>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            ip_access_control_list_sid: ip_access_control_list_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListIpAddresses {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/IpAccessControlLists/{IpAccessControlListSid}/IpAddresses.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListIpAddressesResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{IpAccessControlListSid}", &self.ip_access_control_list_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListIpAddressesResponse {
+    pub ip_addresses: Vec<IpAddressResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListIpAddressesResponse, ip_addresses, IpAddressResponse);
+
+#[derive(Debug)]
+pub struct UpdateIpAddress<'a> {
+    pub account_sid: String,
+    pub ip_access_control_list_sid: String,
+    pub ip_address_sid: String,
+    pub body: RequestBody<UpdateIpAddressBody<'a>>,
+}
+
+impl<'a> UpdateIpAddress<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        ip_access_control_list_sid: impl Into<String>,
+        ip_address_sid: impl Into<String>,
+        body: UpdateIpAddressBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            ip_access_control_list_sid: ip_access_control_list_sid.into(),
+            ip_address_sid: ip_address_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateIpAddressBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cidr_prefix_length: Option<u8>,
+}
+
+impl TwilioEndpoint for UpdateIpAddress<'_> {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/IpAccessControlLists/{IpAccessControlListSid}/IpAddresses/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = IpAddressResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{IpAccessControlListSid}", &self.ip_access_control_list_sid),
+            ("{Sid}", &self.ip_address_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteIpAddress {
+    pub account_sid: String,
+    pub ip_access_control_list_sid: String,
+    pub ip_address_sid: String,
+}
+
+impl DeleteIpAddress {
+    pub fn new(
+        account_sid: impl Into<String>,
+        ip_access_control_list_sid: impl Into<String>,
+        ip_address_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            ip_access_control_list_sid: ip_access_control_list_sid.into(),
+            ip_address_sid: ip_address_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteIpAddress {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/IpAccessControlLists/{IpAccessControlListSid}/IpAddresses/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{IpAccessControlListSid}", &self.ip_access_control_list_sid),
+            ("{Sid}", &self.ip_address_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [IpAccessControlListMapping Properties](https://www.twilio.com/docs/voice/sip/api/sip-ipaccesscontrollistmapping-resource#ipaccesscontrollistmapping-properties)
+pub struct IpAccessControlListMappingResponse {
+    /// The unique id of the Account that is responsible for this resource.
+    pub account_sid: String,
+    /// The date that this resource was created, given as GMT in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date that this resource was last updated, given as GMT in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// A human readable descriptive text for this resource, up to 64 characters long.
+    pub friendly_name: Option<String>,
+    /// The SID of the mapped IpAccessControlList.
+    pub sid: String,
+    /// The URIs of the mapped IpAccessControlList's subresources.
+    pub subresource_uris: Option<serde_json::Value>,
+    /// The URI for this resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+}
+
+/// Makes a SIP domain accept calls from the addresses in an IP access control list.
+#[derive(Debug)]
+pub struct CreateIpAccessControlListMapping<'a> {
+    pub account_sid: String,
+    pub domain_sid: String,
+    pub body: RequestBody<CreateIpAccessControlListMappingBody<'a>>,
+}
+
+impl<'a> CreateIpAccessControlListMapping<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        domain_sid: impl Into<String>,
+        body: CreateIpAccessControlListMappingBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            domain_sid: domain_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateIpAccessControlListMappingBody<'a> {
+    pub ip_access_control_list_sid: &'a str,
+}
+
+impl TwilioEndpoint for CreateIpAccessControlListMapping<'_> {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/Domains/{DomainSid}/IpAccessControlListMappings.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = IpAccessControlListMappingResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{DomainSid}", &self.domain_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchIpAccessControlListMapping {
+    pub account_sid: String,
+    pub domain_sid: String,
+    pub ip_access_control_list_sid: String,
+}
+
+impl FetchIpAccessControlListMapping {
+    pub fn new(
+        account_sid: impl Into<String>,
+        domain_sid: impl Into<String>,
+        ip_access_control_list_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            domain_sid: domain_sid.into(),
+            ip_access_control_list_sid: ip_access_control_list_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchIpAccessControlListMapping {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/Domains/{DomainSid}/IpAccessControlListMappings/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = IpAccessControlListMappingResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{DomainSid}", &self.domain_sid),
+            ("{Sid}", &self.ip_access_control_list_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListIpAccessControlListMappings {
+    pub account_sid: String,
+    pub domain_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListIpAccessControlListMappings {
+    pub fn new(
+        account_sid: impl Into<String>,
+        domain_sid: impl Into<String>,
+        query: TwilioQuery<Self>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            domain_sid: domain_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListIpAccessControlListMappings {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/Domains/{DomainSid}/IpAccessControlListMappings.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListIpAccessControlListMappingsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{DomainSid}", &self.domain_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListIpAccessControlListMappingsResponse {
+    pub ip_access_control_list_mappings: Vec<IpAccessControlListMappingResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(
+    ListIpAccessControlListMappingsResponse,
+    ip_access_control_list_mappings,
+    IpAccessControlListMappingResponse
+);
+
+/// Stops a SIP domain from accepting calls from an IP access control list's addresses.
+#[derive(Clone, Debug)]
+pub struct DeleteIpAccessControlListMapping {
+    pub account_sid: String,
+    pub domain_sid: String,
+    pub ip_access_control_list_sid: String,
+}
+
+impl DeleteIpAccessControlListMapping {
+    pub fn new(
+        account_sid: impl Into<String>,
+        domain_sid: impl Into<String>,
+        ip_access_control_list_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            domain_sid: domain_sid.into(),
+            ip_access_control_list_sid: ip_access_control_list_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteIpAccessControlListMapping {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/SIP/Domains/{DomainSid}/IpAccessControlListMappings/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{DomainSid}", &self.domain_sid),
+            ("{Sid}", &self.ip_access_control_list_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn list_ip_addresses_response_is_deserializing_cidr_prefix() {
+        let json = r#"{"ip_addresses": [{"account_sid": "AC123", "cidr_prefix_length": 24, "date_created": "Mon, 20 Jul 2015 17:27:10 +0000", "date_updated": "Mon, 20 Jul 2015 17:27:10 +0000", "friendly_name": "office", "ip_access_control_list_sid": "AL123", "ip_address": "192.168.1.0", "sid": "IP123", "uri": "/uri"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListIpAddressesResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(resp.ip_addresses[0].ip_address, "192.168.1.0");
+        assert_eq!(resp.ip_addresses[0].cidr_prefix_length, Some(24));
+    }
+
+    #[test]
+    fn create_ip_address_body_is_serializing_cidr_block() {
+        let body = CreateIpAddressBody {
+            cidr_prefix_length: Some(24),
+            ..CreateIpAddressBody::new("office", "192.168.1.0")
+        };
+
+        assert_eq!(
+            serde_urlencoded::to_string(&body).unwrap(),
+            "FriendlyName=office&IpAddress=192.168.1.0&CidrPrefixLength=24"
+        );
+    }
+
+    #[test]
+    fn create_ip_access_control_list_mapping_is_scoping_path_to_domain() {
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let body = CreateIpAccessControlListMappingBody {
+            ip_access_control_list_sid: "AL123",
+        };
+        let url = CreateIpAccessControlListMapping::new("AC123", "SD123", body).url(&base_url);
+
+        assert_eq!(
+            url.path(),
+            "/2010-04-01/Accounts/AC123/SIP/Domains/SD123/IpAccessControlListMappings.json"
+        );
+    }
+}
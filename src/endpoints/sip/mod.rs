@@ -0,0 +1,5 @@
+//! SIP Interface endpoints
+//! See [SIP Interface API](https://www.twilio.com/docs/voice/sip/api)
+use super::*;
+pub mod credential_lists;
+pub mod ip_access_control_lists;
@@ -0,0 +1,117 @@
+//! Usage endpoints
+//! See [Usage](https://www.twilio.com/docs/usage/api)
+use super::*;
+pub mod records;
+pub mod triggers;
+
+macro_rules! usage_categories {
+    ($($variant:ident => $name:literal,)+) => {
+        /// The usage categories most applications bill or alert on.
+        ///
+        /// See [Usage Categories](https://www.twilio.com/docs/usage/api/usage-record#usage-categories)
+        /// for the full list; any other category is kept as [`UsageCategory::Other`].
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum UsageCategory {
+            $(#[doc = $name] $variant,)+
+            /// A category this version of the crate doesn't list, by its Twilio name.
+            Other(String),
+        }
+
+        impl UsageCategory {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(UsageCategory::$variant => $name,)+
+                    UsageCategory::Other(name) => name,
+                }
+            }
+        }
+
+        impl From<&str> for UsageCategory {
+            fn from(name: &str) -> Self {
+                match name {
+                    $($name => UsageCategory::$variant,)+
+                    other => UsageCategory::Other(other.to_string()),
+                }
+            }
+        }
+    };
+}
+
+usage_categories! {
+    Calls => "calls",
+    CallsInbound => "calls-inbound",
+    CallsInboundLocal => "calls-inbound-local",
+    CallsInboundTollfree => "calls-inbound-tollfree",
+    CallsOutbound => "calls-outbound",
+    CallsClient => "calls-client",
+    CallsSip => "calls-sip",
+    Sms => "sms",
+    SmsInbound => "sms-inbound",
+    SmsOutbound => "sms-outbound",
+    Mms => "mms",
+    MmsInbound => "mms-inbound",
+    MmsOutbound => "mms-outbound",
+    PhoneNumbers => "phonenumbers",
+    Recordings => "recordings",
+    RecordingStorage => "recordingstorage",
+    Transcriptions => "transcriptions",
+    Lookups => "lookups",
+    TotalPrice => "totalprice",
+}
+
+impl From<String> for UsageCategory {
+    fn from(name: String) -> Self {
+        UsageCategory::from(name.as_str())
+    }
+}
+
+impl std::fmt::Display for UsageCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for UsageCategory {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for UsageCategory {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        Ok(String::deserialize(deserializer)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usage_category_is_round_tripping_twilio_names() {
+        let category: UsageCategory = serde_json::from_str(r#""calls-inbound""#).unwrap();
+        assert_eq!(category, UsageCategory::CallsInbound);
+
+        assert_eq!(UsageCategory::TotalPrice.to_string(), "totalprice");
+        assert_eq!(
+            serde_json::to_string(&UsageCategory::RecordingStorage).unwrap(),
+            r#""recordingstorage""#
+        );
+    }
+
+    #[test]
+    fn usage_category_is_keeping_unlisted_names() {
+        let category: UsageCategory = serde_json::from_str(r#""wireless-usage""#).unwrap();
+
+        assert_eq!(category, UsageCategory::Other("wireless-usage".to_string()));
+        assert_eq!(
+            serde_json::to_string(&category).unwrap(),
+            r#""wireless-usage""#
+        );
+    }
+}
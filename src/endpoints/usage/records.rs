@@ -0,0 +1,221 @@
+//! Usage Record endpoints
+//! See [UsageRecord resource reference](https://www.twilio.com/docs/usage/api/usage-record)
+use super::*;
+use crate::endpoints::applications::ApiVersion;
+use crate::url::query::{TwilioQuery, UsageRecordQueryMarker};
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [UsageRecord Properties](https://www.twilio.com/docs/usage/api/usage-record#usagerecord-properties)
+pub struct UsageRecordResponse {
+    /// The SID of the Account that accrued the usage.
+    pub account_sid: String,
+    /// The API version used to create the resource.
+    pub api_version: Option<ApiVersion>,
+    /// Usage records up to date as of this timestamp, formatted as YYYY-MM-DDTHH:MM:SS+00:00.
+    pub as_of: Option<String>,
+    /// The category of usage.
+    pub category: UsageCategory,
+    /// The number of usage events, such as the number of calls.
+    pub count: Option<String>,
+    /// The units in which `count` is measured, such as `calls` for calls or `messages` for SMS.
+    pub count_unit: Option<String>,
+    /// A plain-language description of the usage category.
+    pub description: Option<String>,
+    /// The last date for which usage is included in the UsageRecord, formatted as YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// The total price of the usage in the currency specified in `price_unit`.
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub price: Option<String>,
+    /// The currency in which `price` is measured, in ISO 4127 format (e.g. `usd`, `eur`, `jpy`).
+    pub price_unit: Option<String>,
+    /// The first date for which usage is included in this UsageRecord, formatted as YYYY-MM-DD.
+    pub start_date: Option<String>,
+    /// The URI of the resource, relative to `https://api.twilio.com`.
+    pub uri: Option<String>,
+    /// The amount used to bill usage and measured in units described in `usage_unit`.
+    pub usage: Option<String>,
+    /// The units in which `usage` is measured, such as `minutes` for calls or `messages` for SMS.
+    pub usage_unit: Option<String>,
+}
+
+/// Twilio sends `price` as a JSON number for some categories and a string for others.
+fn deserialize_amount<'de, D>(deserializer: D) -> std::result::Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Amount {
+        Text(String),
+        Number(serde_json::Number),
+    }
+
+    Ok(
+        Option::<Amount>::deserialize(deserializer)?.map(|amount| match amount {
+            Amount::Text(text) => text,
+            Amount::Number(number) => number.to_string(),
+        }),
+    )
+}
+
+/// The windows usage records can be listed for, each a sub-list of `Usage/Records`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UsagePeriod {
+    /// One record per category per day.
+    Daily,
+    /// One record per category per month.
+    Monthly,
+    /// One record per category per year.
+    Yearly,
+    /// One record per category over the account's lifetime.
+    AllTime,
+    Today,
+    Yesterday,
+    ThisMonth,
+    LastMonth,
+}
+
+impl UsagePeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UsagePeriod::Daily => "Daily",
+            UsagePeriod::Monthly => "Monthly",
+            UsagePeriod::Yearly => "Yearly",
+            UsagePeriod::AllTime => "AllTime",
+            UsagePeriod::Today => "Today",
+            UsagePeriod::Yesterday => "Yesterday",
+            UsagePeriod::ThisMonth => "ThisMonth",
+            UsagePeriod::LastMonth => "LastMonth",
+        }
+    }
+}
+
+impl UsageRecordQueryMarker for ListUsageRecords {}
+
+/// Lists one record per category for the requested date range.
+#[derive(Clone, Debug)]
+pub struct ListUsageRecords {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListUsageRecords {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListUsageRecords {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Usage/Records.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListUsageRecordsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+impl UsageRecordQueryMarker for ListUsageRecordsForPeriod {}
+
+/// Lists usage records broken down by, or limited to, a [`UsagePeriod`].
+#[derive(Clone, Debug)]
+pub struct ListUsageRecordsForPeriod {
+    pub account_sid: String,
+    pub period: UsagePeriod,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListUsageRecordsForPeriod {
+    pub fn new(
+        account_sid: impl Into<String>,
+        period: UsagePeriod,
+        query: TwilioQuery<Self>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            period,
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListUsageRecordsForPeriod {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Usage/Records/{Period}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListUsageRecordsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Period}", self.period.as_str()),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListUsageRecordsResponse {
+    pub usage_records: Vec<UsageRecordResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListUsageRecordsResponse, usage_records, UsageRecordResponse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn list_usage_records_response_is_accepting_numeric_and_string_prices() {
+        let json = r#"{"usage_records": [{"account_sid": "AC123", "api_version": "2010-04-01", "as_of": "2019-06-24T22:32:49+00:00", "category": "calls", "count": "16", "count_unit": "calls", "description": "Voice Minutes", "end_date": "2024-01-31", "price": 0.63, "price_unit": "usd", "start_date": "2024-01-01", "uri": "/uri", "usage": "20", "usage_unit": "minutes"}, {"account_sid": "AC123", "category": "wireless-usage", "price": "1.25"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 1, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListUsageRecordsResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 2);
+        assert_eq!(resp.usage_records[0].category, UsageCategory::Calls);
+        assert_eq!(resp.usage_records[0].price.as_deref(), Some("0.63"));
+        assert_eq!(
+            resp.usage_records[1].category,
+            UsageCategory::Other("wireless-usage".to_string())
+        );
+        assert_eq!(resp.usage_records[1].price.as_deref(), Some("1.25"));
+    }
+
+    #[test]
+    fn list_usage_records_for_period_is_querying_category_and_dates() {
+        let query = TwilioQuery::new()
+            .with_category(UsageCategory::SmsOutbound)
+            .with_start_date("2024-01-01")
+            .with_end_date("2024-01-31");
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = ListUsageRecordsForPeriod::new("AC123", UsagePeriod::Daily, query).url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/Usage/Records/Daily.json?Category=sms-outbound&StartDate=2024-01-01&EndDate=2024-01-31"
+        );
+    }
+}
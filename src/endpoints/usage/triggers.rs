@@ -0,0 +1,435 @@
+//! Usage Trigger endpoints
+//! See [UsageTrigger resource reference](https://www.twilio.com/docs/usage/api/usage-trigger)
+use super::*;
+use crate::endpoints::applications::ApiVersion;
+use crate::url::query::{TwilioQuery, UsageTriggerQueryMarker};
+use strum::Display;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [UsageTrigger Properties](https://www.twilio.com/docs/usage/api/usage-trigger#usagetrigger-properties)
+pub struct UsageTriggerResponse {
+    /// The SID of the Account that created the trigger monitors.
+    pub account_sid: String,
+    /// The API version used to create the resource.
+    pub api_version: Option<ApiVersion>,
+    /// The HTTP method we use to call `callback_url`. Can be: `GET` or `POST`.
+    pub callback_method: Option<String>,
+    /// The URL we call using the `callback_method` when the trigger fires.
+    pub callback_url: Option<String>,
+    /// The current value of the field the trigger is watching.
+    pub current_value: Option<String>,
+    /// The date and time in GMT that the resource was created specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT that the trigger was last fired specified in RFC 2822 format.
+    pub date_fired: Option<String>,
+    /// The date and time in GMT that the resource was last updated specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// The string that you assigned to describe the trigger.
+    pub friendly_name: Option<String>,
+    /// The frequency of a recurring UsageTrigger, `None` for a trigger that fires once.
+    #[serde(default, deserialize_with = "deserialize_recurring")]
+    pub recurring: Option<Recurring>,
+    /// The unique string that we created to identify the UsageTrigger resource.
+    pub sid: String,
+    /// The field in the UsageRecord resource that fires the trigger.
+    pub trigger_by: Option<TriggerBy>,
+    /// The value at which the trigger will fire.
+    pub trigger_value: Option<String>,
+    /// The URI of the resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+    /// The usage category the trigger watches.
+    pub usage_category: Option<UsageCategory>,
+    /// The URI of the UsageRecord resource this trigger watches, relative to
+    /// `https://api.twilio.com`.
+    pub usage_record_uri: Option<String>,
+}
+
+/// How often a trigger resets and can fire again.
+#[derive(Clone, Debug, Deserialize, Display, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Recurring {
+    Daily,
+    Monthly,
+    Yearly,
+    Alltime,
+}
+
+/// Twilio sends an empty `recurring` for a trigger that fires once.
+fn deserialize_recurring<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Recurring>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)?.as_deref() {
+        None | Some("") => Ok(None),
+        Some(recurring) => {
+            Recurring::deserialize(serde::de::value::StrDeserializer::new(recurring)).map(Some)
+        }
+    }
+}
+
+/// The UsageRecord field a trigger compares against its `trigger_value`.
+#[derive(Clone, Debug, Default, Deserialize, Display, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum TriggerBy {
+    /// Twilio's default.
+    #[default]
+    Usage,
+    Count,
+    Price,
+}
+
+/// The parameters Twilio sends to `callback_url` when a trigger fires.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UsageTriggerCallbackParams {
+    pub account_sid: String,
+    pub usage_trigger_sid: String,
+    /// When the trigger fired, in RFC 2822 format.
+    pub date_fired: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_recurring")]
+    pub recurring: Option<Recurring>,
+    pub usage_category: UsageCategory,
+    pub trigger_by: TriggerBy,
+    pub trigger_value: String,
+    /// The value of the watched field when the trigger fired.
+    pub current_value: String,
+    pub usage_record_uri: Option<String>,
+    /// The same for every retry of one firing, so repeated deliveries can be dropped.
+    pub idempotency_token: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct CreateUsageTrigger<'a> {
+    pub account_sid: String,
+    pub body: RequestBody<CreateUsageTriggerBody<'a>>,
+}
+
+impl<'a> CreateUsageTrigger<'a> {
+    pub fn new(account_sid: impl Into<String>, body: CreateUsageTriggerBody<'a>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateUsageTriggerBody<'a> {
+    pub callback_url: &'a str,
+    pub trigger_value: &'a str,
+    pub usage_category: UsageCategory,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+    /// Leave unset for a trigger that fires once.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<Recurring>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_by: Option<TriggerBy>,
+}
+
+impl<'a> CreateUsageTriggerBody<'a> {
+    pub fn new(
+        callback_url: &'a str,
+        trigger_value: &'a str,
+        usage_category: impl Into<UsageCategory>,
+    ) -> Self {
+        Self {
+            callback_url,
+            trigger_value,
+            usage_category: usage_category.into(),
+            callback_method: None,
+            friendly_name: None,
+            recurring: None,
+            trigger_by: None,
+        }
+    }
+}
+
+impl TwilioEndpoint for CreateUsageTrigger<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Usage/Triggers.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = UsageTriggerResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchUsageTrigger {
+    pub account_sid: String,
+    pub usage_trigger_sid: String,
+}
+
+impl FetchUsageTrigger {
+    pub fn new(account_sid: impl Into<String>, usage_trigger_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            usage_trigger_sid: usage_trigger_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchUsageTrigger {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Usage/Triggers/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = UsageTriggerResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.usage_trigger_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+impl UsageTriggerQueryMarker for ListUsageTriggers {}
+
+#[derive(Clone, Debug)]
+pub struct ListUsageTriggers {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListUsageTriggers {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListUsageTriggers {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Usage/Triggers.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListUsageTriggersResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListUsageTriggersResponse {
+    pub usage_triggers: Vec<UsageTriggerResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(
+    ListUsageTriggersResponse,
+    usage_triggers,
+    UsageTriggerResponse
+);
+
+#[derive(Debug)]
+pub struct UpdateUsageTrigger<'a> {
+    pub account_sid: String,
+    pub usage_trigger_sid: String,
+    pub body: RequestBody<UpdateUsageTriggerBody<'a>>,
+}
+
+impl<'a> UpdateUsageTrigger<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        usage_trigger_sid: impl Into<String>,
+        body: UpdateUsageTriggerBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            usage_trigger_sid: usage_trigger_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateUsageTriggerBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+}
+
+impl TwilioEndpoint for UpdateUsageTrigger<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Usage/Triggers/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = UsageTriggerResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.usage_trigger_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteUsageTrigger {
+    pub account_sid: String,
+    pub usage_trigger_sid: String,
+}
+
+impl DeleteUsageTrigger {
+    pub fn new(account_sid: impl Into<String>, usage_trigger_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            usage_trigger_sid: usage_trigger_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteUsageTrigger {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Usage/Triggers/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.usage_trigger_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    const TRIGGER: &str = r#"{"account_sid": "AC123", "api_version": "2010-04-01", "callback_method": "POST", "callback_url": "https://example.com/spend", "current_value": "12.50", "date_created": "Sun, 06 Sep 2015 12:58:45 +0000", "date_fired": null, "date_updated": "Sun, 06 Sep 2015 12:58:45 +0000", "friendly_name": "monthly spend", "recurring": "monthly", "sid": "UT123", "trigger_by": "price", "trigger_value": "100", "uri": "/2010-04-01/Accounts/AC123/Usage/Triggers/UT123.json", "usage_category": "totalprice", "usage_record_uri": "/2010-04-01/Accounts/AC123/Usage/Records/ThisMonth.json?Category=totalprice"}"#;
+
+    #[test]
+    fn usage_trigger_response_is_deserializing() {
+        let trigger: UsageTriggerResponse = serde_json::from_str(TRIGGER).unwrap();
+
+        assert_eq!(trigger.sid, "UT123");
+        assert_eq!(trigger.trigger_value.as_deref(), Some("100"));
+        assert_eq!(trigger.usage_category, Some(UsageCategory::TotalPrice));
+        assert_eq!(trigger.trigger_by, Some(TriggerBy::Price));
+        assert!(trigger.date_fired.is_none());
+    }
+
+    #[test]
+    fn list_usage_triggers_response_is_deserializing() {
+        let json = format!(
+            r#"{{"usage_triggers": [{TRIGGER}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}}"#
+        );
+        let resp: ListUsageTriggersResponse = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(resp.usage_triggers[0].recurring, Some(Recurring::Monthly));
+    }
+
+    #[test]
+    fn create_usage_trigger_body_is_serializing_required_fields() {
+        let body = CreateUsageTriggerBody {
+            recurring: Some(Recurring::Daily),
+            ..CreateUsageTriggerBody::new(
+                "https://example.com/spend",
+                "100",
+                UsageCategory::TotalPrice,
+            )
+        };
+
+        assert_eq!(
+            serde_urlencoded::to_string(&body).unwrap(),
+            "CallbackUrl=https%3A%2F%2Fexample.com%2Fspend&TriggerValue=100&UsageCategory=totalprice&Recurring=daily"
+        );
+    }
+
+    #[test]
+    fn create_usage_trigger_body_is_sending_unlisted_category() {
+        let body = CreateUsageTriggerBody::new("https://example.com/sims", "10", "wireless-usage");
+
+        assert_eq!(
+            serde_urlencoded::to_string(&body).unwrap(),
+            "CallbackUrl=https%3A%2F%2Fexample.com%2Fsims&TriggerValue=10&UsageCategory=wireless-usage"
+        );
+    }
+
+    #[test]
+    fn list_usage_triggers_is_querying_usage_category() {
+        let query = TwilioQuery::new().with_usage_category("wireless-usage");
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = ListUsageTriggers::new("AC123", query).url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/Usage/Triggers.json?UsageCategory=wireless-usage"
+        );
+    }
+
+    #[test]
+    fn usage_trigger_response_is_reading_empty_recurring_as_none() {
+        let json = TRIGGER.replace(r#""recurring": "monthly""#, r#""recurring": """#);
+        let trigger: UsageTriggerResponse = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(trigger.recurring, None);
+    }
+
+    #[test]
+    fn usage_trigger_callback_params_is_deserializing_form() {
+        let form = "AccountSid=AC123&UsageTriggerSid=UT123&DateFired=Sun%2C+06+Sep+2015+12%3A58%3A45+%2B0000&Recurring=&UsageCategory=calls-inbound&TriggerBy=count&TriggerValue=1000&CurrentValue=1001&UsageRecordUri=%2Furi&IdempotencyToken=AC123-UT123";
+        let params: UsageTriggerCallbackParams = serde_urlencoded::from_str(form).unwrap();
+
+        assert_eq!(params.usage_trigger_sid, "UT123");
+        assert_eq!(params.recurring, None);
+        assert_eq!(params.usage_category, UsageCategory::CallsInbound);
+        assert_eq!(params.trigger_by, TriggerBy::Count);
+        assert_eq!(params.current_value, "1001");
+    }
+}
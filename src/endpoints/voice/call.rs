@@ -2,11 +2,15 @@
 //! See [Call Resource reference](https://www.twilio.com/docs/voice/api/call-resource)
 #![allow(unused_imports)]
 use super::*;
+use crate::callback_urls::require_http;
 use crate::endpoints::applications::ApiVersion;
+use crate::endpoints::voice::recording::ListRecordings;
+use crate::error::TwilioError;
 use crate::url::query::{ByToAndFrom, CallQueryMarker, TwilioQuery};
 use std::collections::HashMap;
 use std::string::ToString;
 use strum::Display;
+use validator::Validate;
 
 #[derive(Clone, Debug, Deserialize)]
 /// See [Call Properties](https://www.twilio.com/docs/voice/api/call-resource#call-properties)
@@ -67,7 +71,7 @@ pub struct CallResponse {
     /// by a <Dial> verb. Using Elastic SIP Trunking, the values can be trunking-terminating
     /// for outgoing calls from your communications infrastructure to the PSTN or
     /// trunking-originating for incoming calls to your communications infrastructure from the PSTN.
-    pub direction: Option<String>,
+    pub direction: Option<CallDirection>,
     /// Either human or machine if this call was initiated with answering machine detection.
     /// Empty otherwise.
     pub answered_by: Option<String>,
@@ -93,9 +97,45 @@ pub struct CallResponse {
     /// https://api.twilio.com.
     //pub subresource_uris: Option<UriMap>,
     pub subresource_uris: Option<serde_json::Value>,
+    /// A billing annotation Twilio attached to the call. Deprecated by Twilio and read-only:
+    /// the Calls API has no parameter to set it.
     pub annotation: Option<String>,
 }
 
+impl CallResponse {
+    /// Lists the recordings made during this call.
+    pub fn recordings(&self) -> ListRecordings {
+        ListRecordings::new(
+            &self.account_sid,
+            TwilioQuery::new().with_call_sid(&self.sid),
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl CallResponse {
+    /// How long the call has lasted, from `start_time` to `end_time` or, while the call is
+    /// still ongoing, to now. `None` if the call never started.
+    ///
+    /// Unlike `duration` this is also set for ongoing calls, but only to the second.
+    pub fn computed_duration(&self) -> Option<std::time::Duration> {
+        self.computed_duration_at(chrono::Utc::now())
+    }
+
+    fn computed_duration_at(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<std::time::Duration> {
+        let parse = |time: &str| chrono::DateTime::parse_from_rfc2822(time).ok();
+        let start = parse(self.start_time.as_deref()?)?;
+        let end = match self.end_time.as_deref().filter(|end| !end.is_empty()) {
+            Some(end) => parse(end)?.to_utc(),
+            None => now,
+        };
+        end.signed_duration_since(start).to_std().ok()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Display, Serialize, PartialEq)]
 /// See [Call Status](https://www.twilio.com/docs/voice/api/call-resource#call-status-values)
 #[serde(rename_all = "kebab-case")]
@@ -111,6 +151,41 @@ pub enum CallStatus {
     NoAnswer,
 }
 
+#[derive(Clone, Debug, Deserialize, Display, Serialize, PartialEq)]
+/// The direction of a call leg, see [`CallResponse::direction`].
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum CallDirection {
+    Inbound,
+    OutboundApi,
+    OutboundDial,
+    TrunkingTerminating,
+    TrunkingOriginating,
+    /// A direction this crate doesn't know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+impl CallDirection {
+    /// Whether the leg was placed by Twilio rather than received by it. Trunking-terminating
+    /// legs leave your infrastructure for the PSTN, so they count as outbound.
+    pub fn is_outbound(&self) -> bool {
+        matches!(
+            self,
+            CallDirection::OutboundApi
+                | CallDirection::OutboundDial
+                | CallDirection::TrunkingTerminating
+        )
+    }
+
+    pub fn is_inbound(&self) -> bool {
+        matches!(
+            self,
+            CallDirection::Inbound | CallDirection::TrunkingOriginating
+        )
+    }
+}
+
 #[derive(Debug)]
 pub struct CreateCall<'a> {
     pub account_sid: String,
@@ -124,8 +199,42 @@ impl<'a> CreateCall<'a> {
             body: RequestBody::Form(body),
         }
     }
+
+    /// Creates the call on the account of the client that sends it.
+    pub fn on(body: CreateCallBody<'a>) -> Self {
+        Self::new("", body)
+    }
+
+    /// Creates a call executing inline `twiml`, see [`CreateCallBody::with_twiml`].
+    pub fn with_twiml(
+        account_sid: impl Into<String>,
+        to: &'a str,
+        from: &'a str,
+        twiml: &'a str,
+    ) -> Result<Self> {
+        let body = CreateCallBody {
+            to,
+            from,
+            ..Default::default()
+        }
+        .with_twiml(twiml)?;
+        Ok(Self::new(account_sid, body))
+    }
+
+    /// Creates a call fetching its TwiML from `url`.
+    pub fn with_url(
+        account_sid: impl Into<String>,
+        to: &'a str,
+        from: &'a str,
+        url: &'a str,
+    ) -> Self {
+        Self::new(account_sid, CreateCallBody::new(to, from, url))
+    }
 }
 
+/// The largest inline TwiML Twilio accepts when creating a call.
+pub const MAX_TWIML_BYTES: usize = 4096;
+
 impl<'a> CreateCallBody<'a> {
     pub fn new(to: &'a str, from: &'a str, url: &'a str) -> Self {
         Self {
@@ -135,9 +244,104 @@ impl<'a> CreateCallBody<'a> {
             ..Default::default()
         }
     }
+
+    /// Sets inline TwiML, erring with [`TwilioError::TwimlTooLarge`] if it exceeds
+    /// [`MAX_TWIML_BYTES`] rather than letting the API reject the call.
+    pub fn with_twiml(mut self, twiml: &'a str) -> Result<Self> {
+        if twiml.len() > MAX_TWIML_BYTES {
+            return Err(TwilioError::TwimlTooLarge(twiml.len()));
+        }
+        self.twiml = Some(twiml);
+        Ok(self)
+    }
+
+    /// Records the call, trimming leading and trailing silence unless overridden with
+    /// [`CreateCallBody::with_trim`].
+    pub fn with_record(mut self) -> Self {
+        self.record = Some(true);
+        self.trim = Some(Trim::default());
+        self
+    }
+
+    pub fn with_trim(mut self, trim: Trim) -> Self {
+        self.trim = Some(trim);
+        self
+    }
+
+    /// Sets the URL Twilio fetches the call's TwiML from, erring with
+    /// [`TwilioError::InvalidCallbackUrl`] unless it's an HTTP(S) URL.
+    pub fn with_url(mut self, url: &'a str) -> Result<Self> {
+        require_http(url)?;
+        self.url = Some(url);
+        Ok(self)
+    }
+
+    /// Like [`CreateCallBody::with_url`] for the TwiML fetched when `url` fails.
+    pub fn with_fallback_url(mut self, url: &'a str) -> Result<Self> {
+        require_http(url)?;
+        self.fallback_url = Some(url);
+        Ok(self)
+    }
+
+    /// Sets the call progress callback, erring with [`TwilioError::InvalidCallbackUrl`]
+    /// unless it's an HTTP(S) URL.
+    pub fn with_status_callback(mut self, url: &'a str) -> Result<Self> {
+        require_http(url)?;
+        self.status_callback = Some(url);
+        Ok(self)
+    }
+
+    /// Like [`CreateCallBody::with_status_callback`] for recording status events.
+    pub fn with_recording_status_callback(mut self, url: &'a str) -> Result<Self> {
+        require_http(url)?;
+        self.recording_status_callback = Some(url);
+        Ok(self)
+    }
+
+    /// Sets which recording status events are sent to `recording_status_callback`, each as
+    /// its own `RecordingStatusCallbackEvent` parameter.
+    pub fn with_recording_events(mut self, events: &[RecordingStatusCallbackEvent]) -> Self {
+        [
+            self.recording_status_callback_event_in_progress,
+            self.recording_status_callback_event_completed,
+            self.recording_status_callback_event_absent,
+        ] = RecordingStatusCallbackEvent::flags(events);
+        self
+    }
+
+    /// Like [`CreateCallBody::with_status_callback`] for the async AMD result.
+    pub fn with_async_amd_status_callback(mut self, url: &'a str) -> Result<Self> {
+        require_http(url)?;
+        self.async_amd_status_callback = Some(url);
+        Ok(self)
+    }
+
+    /// Seconds to wait for answering machine detection, between 3 and 59.
+    pub fn with_machine_detection_timeout(mut self, seconds: u32) -> Self {
+        self.machine_detection_timeout = Some(seconds);
+        self
+    }
+
+    /// Milliseconds of speech after which a machine is detected, between 1000 and 6000.
+    pub fn with_machine_detection_speech_threshold(mut self, millis: f32) -> Self {
+        self.machine_detection_speech_threshold = Some(millis);
+        self
+    }
+
+    /// Milliseconds of silence after speech that ends detection, between 500 and 5000.
+    pub fn with_machine_detection_speech_end_threshold(mut self, millis: f32) -> Self {
+        self.machine_detection_speech_end_threshold = Some(millis);
+        self
+    }
+
+    /// Milliseconds of initial silence after which `unknown` is returned, between 2000 and 10000.
+    pub fn with_machine_detection_silence_timeout(mut self, millis: u32) -> Self {
+        self.machine_detection_silence_timeout = Some(millis);
+        self
+    }
 }
 
-#[derive(Clone, Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct CreateCallBody<'a> {
     pub to: &'a str,
@@ -201,6 +405,7 @@ pub struct CreateCallBody<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub machine_detection: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 3, max = 59))]
     pub machine_detection_timeout: Option<u32>,
     #[serde(
         rename = "RecordingStatusCallbackEvent",
@@ -221,14 +426,17 @@ pub struct CreateCallBody<'a> {
     )]
     pub recording_status_callback_event_absent: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trim: Option<&'a str>,
+    pub trim: Option<Trim>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caller_id: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1000.0, max = 6000.0))]
     pub machine_detection_speech_threshold: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 500.0, max = 5000.0))]
     pub machine_detection_speech_end_threshold: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 2000, max = 10000))]
     pub machine_detection_silence_timeout: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub async_amd: Option<bool>,
@@ -243,7 +451,7 @@ pub struct CreateCallBody<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub call_token: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub recording_track: Option<&'a str>,
+    pub recording_track: Option<RecordingTrack>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_limit: Option<u32>,
 }
@@ -259,10 +467,17 @@ impl TwilioEndpoint for CreateCall<'_> {
         vec![("{AccountSid}", &self.account_sid)]
     }
 
+    fn account_sid_mut(&mut self) -> Option<&mut String> {
+        Some(&mut self.account_sid)
+    }
+
     fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder>
     where
         Self: Sized,
     {
+        if let RequestBody::Form(body) | RequestBody::Json(body) = &self.body {
+            body.validate()?;
+        }
         self.body.configure(builder)
     }
 
@@ -271,7 +486,182 @@ impl TwilioEndpoint for CreateCall<'_> {
     }
 }
 
-#[derive(Clone, Debug, Serialize)]
+/// Builds a [`CreateCall`] from validated groups of settings instead of setting the many
+/// related [`CreateCallBody`] fields one by one.
+///
+/// [`OutboundCall::build`] checks every callback is an HTTP(S) URL and the AMD tuning is in
+/// range, so a misconfigured call fails before it's sent.
+#[derive(Clone, Debug)]
+pub struct OutboundCall<'a> {
+    body: CreateCallBody<'a>,
+    status_callbacks: Option<StatusCallbacks<'a>>,
+    recording: Option<RecordingConfig<'a>>,
+    machine_detection: Option<MachineDetectionConfig<'a>>,
+}
+
+impl<'a> OutboundCall<'a> {
+    pub fn new(to: &'a str, from: &'a str, url: &'a str) -> Self {
+        Self::from_body(CreateCallBody::new(to, from, url))
+    }
+
+    /// Starts from `body`, e.g. one with inline TwiML or a `caller_id`; the groups set on the
+    /// builder override the matching fields.
+    pub fn from_body(body: CreateCallBody<'a>) -> Self {
+        Self {
+            body,
+            status_callbacks: None,
+            recording: None,
+            machine_detection: None,
+        }
+    }
+
+    pub fn with_status_callbacks(mut self, status_callbacks: StatusCallbacks<'a>) -> Self {
+        self.status_callbacks = Some(status_callbacks);
+        self
+    }
+
+    pub fn with_recording(mut self, recording: RecordingConfig<'a>) -> Self {
+        self.recording = Some(recording);
+        self
+    }
+
+    pub fn with_machine_detection(mut self, machine_detection: MachineDetectionConfig<'a>) -> Self {
+        self.machine_detection = Some(machine_detection);
+        self
+    }
+
+    /// Validates the settings and produces the endpoint.
+    pub fn build(self, account_sid: impl Into<String>) -> Result<CreateCall<'a>> {
+        let mut body = self.body;
+
+        if let Some(status) = self.status_callbacks {
+            body = body.with_status_callback(status.url)?;
+            body.status_callback_method = status.method;
+            let wants = |event| Some(status.events.contains(&event));
+            body.status_callback_event_initiated = wants(StatusCallbackEvent::Initiated);
+            body.status_callback_event_ringing = wants(StatusCallbackEvent::Ringing);
+            body.status_callback_event_answered = wants(StatusCallbackEvent::Answered);
+            body.status_callback_event_completed = wants(StatusCallbackEvent::Completed);
+        }
+
+        if let Some(recording) = self.recording {
+            body = body.with_record().with_trim(recording.trim);
+            body.recording_channels = recording.channels;
+            body.recording_track = recording.track;
+            if let Some(url) = recording.status_callback {
+                body = body.with_recording_status_callback(url)?;
+                body.recording_status_callback_method = recording.status_callback_method;
+                body = body.with_recording_events(&recording.status_callback_events);
+            }
+        }
+
+        if let Some(amd) = self.machine_detection {
+            body.machine_detection = Some(amd.mode);
+            body.machine_detection_timeout = amd.timeout;
+            body.machine_detection_speech_threshold = amd.speech_threshold;
+            body.machine_detection_speech_end_threshold = amd.speech_end_threshold;
+            body.machine_detection_silence_timeout = amd.silence_timeout;
+            if let Some(url) = amd.async_status_callback {
+                body = body.with_async_amd_status_callback(url)?;
+                body.async_amd = Some(true);
+                body.async_amd_status_callback_method = amd.async_status_callback_method;
+            }
+        }
+
+        body.validate()?;
+        Ok(CreateCall::new(account_sid, body))
+    }
+}
+
+/// Where call progress events are sent and which ones.
+#[derive(Clone, Debug)]
+pub struct StatusCallbacks<'a> {
+    pub url: &'a str,
+    pub method: Option<&'a str>,
+    /// Twilio only sends `completed` when this is empty.
+    pub events: Vec<StatusCallbackEvent>,
+}
+
+impl<'a> StatusCallbacks<'a> {
+    pub fn new(url: &'a str, events: &[StatusCallbackEvent]) -> Self {
+        Self {
+            url,
+            method: None,
+            events: events.to_vec(),
+        }
+    }
+}
+
+/// Records the call, optionally reporting recording status events.
+#[derive(Clone, Debug, Default)]
+pub struct RecordingConfig<'a> {
+    /// Trims silence unless set to [`Trim::DoNotTrim`].
+    pub trim: Trim,
+    pub track: Option<RecordingTrack>,
+    /// `mono` or `dual`, Twilio defaults to `mono`.
+    pub channels: Option<&'a str>,
+    pub status_callback: Option<&'a str>,
+    pub status_callback_method: Option<&'a str>,
+    /// Twilio only sends `completed` when this is empty.
+    pub status_callback_events: Vec<RecordingStatusCallbackEvent>,
+}
+
+impl<'a> RecordingConfig<'a> {
+    pub fn with_status_callback(
+        mut self,
+        url: &'a str,
+        events: &[RecordingStatusCallbackEvent],
+    ) -> Self {
+        self.status_callback = Some(url);
+        self.status_callback_events = events.to_vec();
+        self
+    }
+}
+
+/// Answering machine detection, optionally reporting the result asynchronously.
+#[derive(Clone, Debug)]
+pub struct MachineDetectionConfig<'a> {
+    /// `Enable` or `DetectMessageEnd`.
+    pub mode: &'a str,
+    pub timeout: Option<u32>,
+    pub speech_threshold: Option<f32>,
+    pub speech_end_threshold: Option<f32>,
+    pub silence_timeout: Option<u32>,
+    /// Sets `AsyncAmd` so the call isn't held while detection runs.
+    pub async_status_callback: Option<&'a str>,
+    pub async_status_callback_method: Option<&'a str>,
+}
+
+impl<'a> MachineDetectionConfig<'a> {
+    /// Returns as soon as a human or machine is detected.
+    pub fn enable() -> Self {
+        Self::with_mode("Enable")
+    }
+
+    /// Waits for the end of a machine's greeting, e.g. to leave a voicemail.
+    pub fn detect_message_end() -> Self {
+        Self::with_mode("DetectMessageEnd")
+    }
+
+    fn with_mode(mode: &'a str) -> Self {
+        Self {
+            mode,
+            timeout: None,
+            speech_threshold: None,
+            speech_end_threshold: None,
+            silence_timeout: None,
+            async_status_callback: None,
+            async_status_callback_method: None,
+        }
+    }
+
+    pub fn with_async_status_callback(mut self, url: &'a str) -> Self {
+        self.async_status_callback = Some(url);
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum StatusCallbackEvent {
     Initiated,
@@ -338,7 +728,7 @@ impl StatusCallbackEvent {
     }
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum RecordingStatusCallbackEvent {
     InProgress,
@@ -347,7 +737,13 @@ pub enum RecordingStatusCallbackEvent {
 }
 
 impl RecordingStatusCallbackEvent {
-    fn serialize_in_progress<S>(
+    /// Maps `events` to the in-progress, completed and absent flags a request body
+    /// serializes as separate `RecordingStatusCallbackEvent` parameters.
+    pub(crate) fn flags(events: &[Self]) -> [Option<bool>; 3] {
+        [Self::InProgress, Self::Completed, Self::Absent].map(|event| Some(events.contains(&event)))
+    }
+
+    pub(crate) fn serialize_in_progress<S>(
         status_callback_event: &Option<bool>,
         serializer: S,
     ) -> std::result::Result<S::Ok, S::Error>
@@ -361,7 +757,7 @@ impl RecordingStatusCallbackEvent {
         }
     }
 
-    fn serialize_completed<S>(
+    pub(crate) fn serialize_completed<S>(
         status_callback_event: &Option<bool>,
         serializer: S,
     ) -> std::result::Result<S::Ok, S::Error>
@@ -375,7 +771,7 @@ impl RecordingStatusCallbackEvent {
         }
     }
 
-    fn serialize_absent<S>(
+    pub(crate) fn serialize_absent<S>(
         status_callback_event: &Option<bool>,
         serializer: S,
     ) -> std::result::Result<S::Ok, S::Error>
@@ -397,7 +793,7 @@ pub struct StatusCallbackParams {
     pub account_sid: String,
     pub call_status: CallStatus,
     pub api_version: ApiVersion,
-    pub direction: String,
+    pub direction: CallDirection,
     pub forwarded_from: Option<String>,
     pub from: String,
     pub to: String,
@@ -415,11 +811,39 @@ pub struct StatusCallbackEventParams {
     pub recording_url: Option<String>,
     pub recording_sid: Option<String>,
     pub recording_duration: Option<String>,
-    pub timestamps: Option<String>,
+    /// When the event fired, as UTC in RFC 2822 format, e.g. `Tue, 23 Mar 2021 18:27:35 +0000`.
+    #[serde(rename = "Timestamp")]
+    pub timestamp: Option<String>,
     pub callback_source: Option<String>,
     pub sequence_number: Option<String>,
 }
 
+impl StatusCallbackEventParams {
+    /// Parses [`StatusCallbackEventParams::timestamp`], `None` if it's missing or malformed.
+    #[cfg(feature = "chrono")]
+    pub fn parsed_timestamp(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let timestamp = self.timestamp.as_deref()?;
+        chrono::DateTime::parse_from_rfc2822(timestamp)
+            .ok()
+            .map(|t| t.to_utc())
+    }
+
+    /// Parses [`StatusCallbackEventParams::sip_response_code`], e.g. `486`.
+    pub fn sip_response_code_num(&self) -> Option<u16> {
+        self.sip_response_code.as_deref()?.trim().parse().ok()
+    }
+
+    /// Whether the callee answered `486 Busy Here`.
+    pub fn is_busy(&self) -> bool {
+        self.sip_response_code_num() == Some(486)
+    }
+
+    /// Whether the callee answered `603 Decline`.
+    pub fn is_declined(&self) -> bool {
+        self.sip_response_code_num() == Some(603)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct RecordingStatusCallbackParams {
@@ -443,8 +867,18 @@ pub enum RecordingStatus {
     Absent,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Whether leading and trailing silence is trimmed from a recording. Twilio trims by default.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Trim {
+    #[default]
+    TrimSilence,
+    DoNotTrim,
+}
+
+#[derive(Clone, Debug, Deserialize, Display, Serialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
 pub enum RecordingTrack {
     Inbound,
     Outbound,
@@ -464,6 +898,11 @@ impl FetchCall {
             call_sid: call_sid.into(),
         }
     }
+
+    /// Fetches the call from the account of the client that sends it.
+    pub fn on(call_sid: impl Into<String>) -> Self {
+        Self::new("", call_sid)
+    }
 }
 
 impl TwilioEndpoint for FetchCall {
@@ -480,6 +919,10 @@ impl TwilioEndpoint for FetchCall {
         ]
     }
 
+    fn account_sid_mut(&mut self) -> Option<&mut String> {
+        Some(&mut self.account_sid)
+    }
+
     async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
         Ok(resp.json().await?)
     }
@@ -501,6 +944,11 @@ impl ListCalls {
             query,
         }
     }
+
+    /// Lists the calls of the account of the client that sends it.
+    pub fn on(query: TwilioQuery<Self>) -> Self {
+        Self::new("", query)
+    }
 }
 
 impl TwilioEndpoint for ListCalls {
@@ -518,6 +966,10 @@ impl TwilioEndpoint for ListCalls {
         vec![("{AccountSid}", &self.account_sid)]
     }
 
+    fn account_sid_mut(&mut self) -> Option<&mut String> {
+        Some(&mut self.account_sid)
+    }
+
     async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
         Ok(resp.json().await?)
     }
@@ -530,6 +982,36 @@ pub struct ListCallsResponse {
     pub pagination: Pagination,
 }
 
+impl_paginated!(ListCallsResponse, calls, CallResponse);
+
+#[cfg(feature = "decimal")]
+impl ListCallsResponse {
+    /// Sums the `price` of the calls in this page, ignoring calls that aren't priced yet,
+    /// and returns it with the shared `price_unit`.
+    ///
+    /// Returns `None` if no call is priced, a price can't be parsed, or the calls are
+    /// priced in different currencies.
+    pub fn total_price(&self) -> Option<(rust_decimal::Decimal, String)> {
+        use std::str::FromStr;
+
+        let mut total: Option<(rust_decimal::Decimal, &str)> = None;
+        for call in &self.calls {
+            let Some(price) = call.price.as_deref() else {
+                continue;
+            };
+            let price = rust_decimal::Decimal::from_str(price).ok()?;
+            let unit = call.price_unit.as_deref()?;
+
+            total = match total {
+                None => Some((price, unit)),
+                Some((sum, currency)) if currency == unit => Some((sum + price, currency)),
+                Some(_) => return None,
+            };
+        }
+        total.map(|(sum, currency)| (sum, currency.to_string()))
+    }
+}
+
 #[derive(Debug)]
 pub struct UpdateCall<'a> {
     pub account_sid: String,
@@ -549,6 +1031,11 @@ impl<'a> UpdateCall<'a> {
             body: RequestBody::Form(body),
         }
     }
+
+    /// Updates the call on the account of the client that sends it.
+    pub fn on(call_sid: impl Into<String>, body: UpdateCallBody<'a>) -> Self {
+        Self::new("", call_sid, body)
+    }
 }
 
 #[derive(Clone, Debug, Default, Serialize)]
@@ -588,15 +1075,38 @@ impl<'a> UpdateCallBody<'a> {
             ..Default::default()
         }
     }
+
+    pub fn status(status: UpdateCallStatus) -> Self {
+        Self {
+            status: Some(status),
+            ..Default::default()
+        }
+    }
 }
 
-#[derive(Clone, Debug, Serialize)]
+/// Ends a call. Which one applies depends on the call's current status, see
+/// [`UpdateCallStatus::ending`].
+#[derive(Clone, Debug, Serialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum UpdateCallStatus {
+    /// Only valid for calls that are `queued` or `ringing`.
     Canceled,
+    /// Only valid for calls that are `in-progress`.
     Completed,
 }
 
+impl UpdateCallStatus {
+    /// The transition that ends a call in `current` status, or
+    /// [`TwilioError::CallNotLive`] if it has already ended.
+    pub fn ending(current: &CallStatus) -> Result<Self> {
+        match current {
+            CallStatus::Queued | CallStatus::Ringing => Ok(UpdateCallStatus::Canceled),
+            CallStatus::InProgress => Ok(UpdateCallStatus::Completed),
+            ended => Err(TwilioError::CallNotLive(ended.clone())),
+        }
+    }
+}
+
 impl TwilioEndpoint for UpdateCall<'_> {
     const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Calls/{Sid}.json";
 
@@ -611,6 +1121,10 @@ impl TwilioEndpoint for UpdateCall<'_> {
         ]
     }
 
+    fn account_sid_mut(&mut self) -> Option<&mut String> {
+        Some(&mut self.account_sid)
+    }
+
     fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder>
     where
         Self: Sized,
@@ -656,3 +1170,440 @@ impl TwilioEndpoint for DeleteCall {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_callback_event_params_is_parsing_timestamp() {
+        let body = "CallStatus=ringing&CallbackSource=call-progress-events&SequenceNumber=1&Timestamp=Tue%2C%2023%20Mar%202021%2018%3A27%3A35%20%2B0000";
+        let params: StatusCallbackEventParams = serde_urlencoded::from_str(body).unwrap();
+
+        assert_eq!(
+            params.timestamp.as_deref(),
+            Some("Tue, 23 Mar 2021 18:27:35 +0000")
+        );
+        #[cfg(feature = "chrono")]
+        assert_eq!(
+            params.parsed_timestamp().unwrap().to_rfc3339(),
+            "2021-03-23T18:27:35+00:00"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn call_response_computed_duration_is_measuring_complete_and_ongoing_calls() {
+        let json = r#"{"sid": "CA123", "account_sid": "AC123", "to": "+15558675310", "from": "+15017122661", "uri": "/uri", "start_time": "Tue, 23 Mar 2021 18:27:35 +0000", "end_time": "Tue, 23 Mar 2021 18:29:05 +0000", "duration": "90"}"#;
+        let mut call: CallResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            call.computed_duration(),
+            Some(std::time::Duration::from_secs(90))
+        );
+
+        call.end_time = None;
+        let now = chrono::DateTime::parse_from_rfc2822("Tue, 23 Mar 2021 18:28:35 +0000")
+            .unwrap()
+            .to_utc();
+        assert_eq!(
+            call.computed_duration_at(now),
+            Some(std::time::Duration::from_secs(60))
+        );
+
+        call.start_time = None;
+        assert_eq!(call.computed_duration(), None);
+    }
+
+    #[test]
+    fn call_response_is_deserializing_annotation_and_listing_recordings() {
+        let json = r#"{"sid": "CA123", "account_sid": "AC123", "to": "+15558675310", "from": "+15017122661", "uri": "/uri", "annotation": "billingreferencetag1"}"#;
+        let call: CallResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(call.annotation.as_deref(), Some("billingreferencetag1"));
+
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        assert_eq!(
+            call.recordings().url(&base_url).as_str(),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/Recordings.json?CallSid=CA123"
+        );
+    }
+
+    #[test]
+    fn outbound_call_is_serializing_every_group() {
+        let call = OutboundCall::new("+15558675310", "+15017122661", "https://example.com/twiml")
+            .with_status_callbacks(StatusCallbacks::new(
+                "https://example.com/status",
+                &[StatusCallbackEvent::Ringing, StatusCallbackEvent::Completed],
+            ))
+            .with_recording(
+                RecordingConfig {
+                    track: Some(RecordingTrack::Both),
+                    channels: Some("dual"),
+                    ..Default::default()
+                }
+                .with_status_callback(
+                    "https://example.com/recording",
+                    &[RecordingStatusCallbackEvent::Completed],
+                ),
+            )
+            .with_machine_detection(
+                MachineDetectionConfig {
+                    timeout: Some(30),
+                    ..MachineDetectionConfig::detect_message_end()
+                }
+                .with_async_status_callback("https://example.com/amd"),
+            )
+            .build("AC123")
+            .unwrap();
+
+        let RequestBody::Form(body) = call.body else {
+            panic!("expected a form body");
+        };
+        assert_eq!(
+            serde_urlencoded::to_string(&body).unwrap(),
+            "To=%2B15558675310&From=%2B15017122661&Url=https%3A%2F%2Fexample.com%2Ftwiml\
+             &StatusCallback=https%3A%2F%2Fexample.com%2Fstatus\
+             &StatusCallbackEvent=ringing&StatusCallbackEvent=completed\
+             &Record=true&RecordingChannels=dual\
+             &RecordingStatusCallback=https%3A%2F%2Fexample.com%2Frecording\
+             &MachineDetection=DetectMessageEnd&MachineDetectionTimeout=30\
+             &RecordingStatusCallbackEvent=completed&Trim=trim-silence\
+             &AsyncAmd=true&AsyncAmdStatusCallback=https%3A%2F%2Fexample.com%2Famd\
+             &RecordingTrack=both"
+        );
+    }
+
+    #[test]
+    fn outbound_call_is_erring_when_config_is_invalid() {
+        let got = OutboundCall::new("+15558675310", "+15017122661", "https://example.com")
+            .with_status_callbacks(StatusCallbacks::new("example.com/status", &[]))
+            .build("AC123");
+        assert!(matches!(got, Err(TwilioError::InvalidCallbackUrl(_))));
+
+        let got = OutboundCall::new("+15558675310", "+15017122661", "https://example.com")
+            .with_machine_detection(MachineDetectionConfig {
+                timeout: Some(60),
+                ..MachineDetectionConfig::enable()
+            })
+            .build("AC123");
+        assert!(matches!(got, Err(TwilioError::Validator(_))));
+    }
+
+    #[test]
+    fn create_call_body_is_erring_when_callback_url_is_not_http() {
+        let body = CreateCallBody::new("+15558675310", "+15017122661", "https://example.com");
+
+        let got = body
+            .clone()
+            .with_status_callback("https://example.com/status")
+            .unwrap();
+        assert_eq!(got.status_callback, Some("https://example.com/status"));
+
+        assert!(matches!(
+            body.clone().with_status_callback("example.com/status"),
+            Err(TwilioError::InvalidCallbackUrl(url)) if url == "example.com/status"
+        ));
+        assert!(matches!(
+            body.clone()
+                .with_recording_status_callback("ftp://example.com"),
+            Err(TwilioError::InvalidCallbackUrl(_))
+        ));
+        assert!(matches!(
+            body.with_async_amd_status_callback("wss://example.com"),
+            Err(TwilioError::InvalidCallbackUrl(_))
+        ));
+    }
+
+    #[test]
+    fn create_call_body_is_erring_when_twiml_url_is_not_http() {
+        let body = CreateCallBody {
+            to: "+15558675310",
+            from: "+15017122661",
+            ..Default::default()
+        };
+
+        let got = body
+            .clone()
+            .with_url("https://example.com/twiml")
+            .and_then(|body| body.with_fallback_url("http://example.com/fallback"))
+            .unwrap();
+        assert_eq!(got.url, Some("https://example.com/twiml"));
+        assert_eq!(got.fallback_url, Some("http://example.com/fallback"));
+
+        assert!(matches!(
+            body.clone().with_url("ftp://example.com/twiml"),
+            Err(TwilioError::InvalidCallbackUrl(url)) if url == "ftp://example.com/twiml"
+        ));
+        assert!(matches!(
+            body.with_fallback_url("ftp://example.com/fallback"),
+            Err(TwilioError::InvalidCallbackUrl(_))
+        ));
+    }
+
+    #[test]
+    fn create_call_body_with_recording_events_is_repeating_param() {
+        let body = CreateCallBody::new("+15558675310", "+15017122661", "https://example.com")
+            .with_recording_events(&[
+                RecordingStatusCallbackEvent::Absent,
+                RecordingStatusCallbackEvent::InProgress,
+            ]);
+        let got = serde_urlencoded::to_string(&body).unwrap();
+
+        assert_eq!(
+            got,
+            "To=%2B15558675310&From=%2B15017122661&Url=https%3A%2F%2Fexample.com\
+             &RecordingStatusCallbackEvent=in-progress&RecordingStatusCallbackEvent=absent"
+        );
+    }
+
+    #[test]
+    fn status_callback_event_params_is_parsing_sip_response_code() {
+        let busy: StatusCallbackEventParams =
+            serde_urlencoded::from_str("CallStatus=busy&SipResponseCode=486").unwrap();
+        assert_eq!(busy.sip_response_code_num(), Some(486));
+        assert!(busy.is_busy());
+        assert!(!busy.is_declined());
+
+        let declined: StatusCallbackEventParams =
+            serde_urlencoded::from_str("CallStatus=busy&SipResponseCode=603").unwrap();
+        assert!(declined.is_declined());
+        assert!(!declined.is_busy());
+
+        let missing: StatusCallbackEventParams =
+            serde_urlencoded::from_str("CallStatus=completed").unwrap();
+        assert_eq!(missing.sip_response_code_num(), None);
+        assert!(!missing.is_busy());
+
+        let malformed: StatusCallbackEventParams =
+            serde_urlencoded::from_str("CallStatus=failed&SipResponseCode=abc").unwrap();
+        assert_eq!(malformed.sip_response_code_num(), None);
+    }
+
+    #[test]
+    fn update_call_status_ending_is_choosing_valid_transition() {
+        assert_eq!(
+            UpdateCallStatus::ending(&CallStatus::Queued).unwrap(),
+            UpdateCallStatus::Canceled
+        );
+        assert_eq!(
+            UpdateCallStatus::ending(&CallStatus::Ringing).unwrap(),
+            UpdateCallStatus::Canceled
+        );
+        assert_eq!(
+            UpdateCallStatus::ending(&CallStatus::InProgress).unwrap(),
+            UpdateCallStatus::Completed
+        );
+        for ended in [
+            CallStatus::Canceled,
+            CallStatus::Completed,
+            CallStatus::Failed,
+            CallStatus::Busy,
+            CallStatus::NoAnswer,
+        ] {
+            let got = UpdateCallStatus::ending(&ended);
+            assert!(matches!(got, Err(TwilioError::CallNotLive(ref s)) if *s == ended));
+        }
+    }
+
+    #[test]
+    fn create_call_body_is_serializing_recording_track() {
+        let body = CreateCallBody {
+            recording_track: Some(RecordingTrack::Both),
+            ..CreateCallBody::new("+15558675310", "+15017122661", "https://example.com")
+        };
+
+        let got = serde_urlencoded::to_string(body.with_record()).unwrap();
+        assert!(got.contains("RecordingTrack=both"), "{got}");
+        assert_eq!(RecordingTrack::Both.to_string(), "both");
+    }
+
+    #[test]
+    fn create_call_body_is_validating_machine_detection_bounds() {
+        let body = CreateCallBody::new("+15558675310", "+15017122661", "https://example.com");
+
+        let in_range = [
+            body.clone()
+                .with_machine_detection_timeout(3)
+                .with_machine_detection_speech_threshold(1000.0)
+                .with_machine_detection_speech_end_threshold(500.0)
+                .with_machine_detection_silence_timeout(2000),
+            body.clone()
+                .with_machine_detection_timeout(59)
+                .with_machine_detection_speech_threshold(6000.0)
+                .with_machine_detection_speech_end_threshold(5000.0)
+                .with_machine_detection_silence_timeout(10000),
+        ];
+        for body in in_range {
+            assert!(body.validate().is_ok());
+        }
+
+        let out_of_range = [
+            body.clone().with_machine_detection_timeout(2),
+            body.clone().with_machine_detection_timeout(60),
+            body.clone().with_machine_detection_speech_threshold(999.0),
+            body.clone().with_machine_detection_speech_threshold(6000.5),
+            body.clone()
+                .with_machine_detection_speech_end_threshold(499.0),
+            body.clone()
+                .with_machine_detection_speech_end_threshold(5001.0),
+            body.clone().with_machine_detection_silence_timeout(1999),
+            body.clone().with_machine_detection_silence_timeout(10001),
+        ];
+        for body in out_of_range {
+            assert!(body.validate().is_err(), "{body:?}");
+        }
+    }
+
+    #[test]
+    fn create_call_is_erring_when_machine_detection_out_of_range() {
+        let body = CreateCallBody::new("+15558675310", "+15017122661", "https://example.com")
+            .with_machine_detection_speech_threshold(7000.0);
+        let builder = reqwest::Client::new().post("https://api.twilio.com");
+
+        let got = CreateCall::new("AC123", body).configure_request_body(builder);
+
+        match got {
+            Err(TwilioError::Validator(errors)) => {
+                assert!(errors
+                    .field_errors()
+                    .contains_key("machine_detection_speech_threshold"));
+            }
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn call_response_direction_is_deriving_inbound_and_outbound() {
+        let call = |direction: &str| -> CallResponse {
+            let json = format!(
+                r#"{{"sid": "CA123", "account_sid": "AC123", "to": "+15558675310", "from": "+15017122661", "uri": "/uri", "direction": "{direction}"}}"#
+            );
+            serde_json::from_str(&json).unwrap()
+        };
+
+        let dial = call("outbound-dial").direction.unwrap();
+        assert_eq!(dial, CallDirection::OutboundDial);
+        assert!(dial.is_outbound() && !dial.is_inbound());
+
+        let inbound = call("trunking-originating").direction.unwrap();
+        assert!(inbound.is_inbound() && !inbound.is_outbound());
+
+        let unknown = call("sideways").direction.unwrap();
+        assert_eq!(unknown, CallDirection::Unknown);
+        assert!(!unknown.is_inbound() && !unknown.is_outbound());
+    }
+
+    #[cfg(feature = "decimal")]
+    fn calls_page(prices: &[(Option<&str>, Option<&str>)]) -> ListCallsResponse {
+        let calls: Vec<String> = prices
+            .iter()
+            .map(|(price, unit)| {
+                let quote = |v: &Option<&str>| v.map_or("null".to_string(), |v| format!(r#""{v}""#));
+                format!(
+                    r#"{{"sid": "CA123", "account_sid": "AC123", "to": "+15558675310", "from": "+15017122661", "uri": "/uri", "price": {}, "price_unit": {}}}"#,
+                    quote(price),
+                    quote(unit)
+                )
+            })
+            .collect();
+        let json = format!(
+            r#"{{"calls": [{}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}}"#,
+            calls.join(", ")
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn list_calls_response_total_price_is_summing_priced_calls() {
+        let page = calls_page(&[
+            (Some("-0.02000"), Some("USD")),
+            (None, None),
+            (Some("-0.01500"), Some("USD")),
+        ]);
+
+        let (total, currency) = page.total_price().unwrap();
+        assert_eq!(total.to_string(), "-0.03500");
+        assert_eq!(currency, "USD");
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn list_calls_response_total_price_is_none_when_currencies_differ() {
+        let page = calls_page(&[
+            (Some("-0.02000"), Some("USD")),
+            (Some("-0.01500"), Some("EUR")),
+        ]);
+        assert!(page.total_price().is_none());
+
+        let page = calls_page(&[(None, None)]);
+        assert!(page.total_price().is_none());
+    }
+
+    #[test]
+    fn create_call_body_with_record_is_trimming_silence_by_default() {
+        let body = CreateCallBody::new("+15558675310", "+15017122661", "https://example.com");
+
+        let got = serde_urlencoded::to_string(body.clone().with_record()).unwrap();
+        assert!(got.ends_with("&Record=true&Trim=trim-silence"), "{got}");
+
+        let got =
+            serde_urlencoded::to_string(body.with_record().with_trim(Trim::DoNotTrim)).unwrap();
+        assert!(got.ends_with("&Record=true&Trim=do-not-trim"), "{got}");
+    }
+
+    #[test]
+    fn create_call_with_twiml_is_setting_body() {
+        let twiml = "<Response><Say>Hi</Say></Response>";
+        let endpoint =
+            CreateCall::with_twiml("AC123", "+15558675310", "+15017122661", twiml).unwrap();
+
+        assert_eq!(endpoint.account_sid, "AC123");
+        let RequestBody::Form(body) = endpoint.body else {
+            panic!("expected form body");
+        };
+        assert_eq!(body.to, "+15558675310");
+        assert_eq!(body.from, "+15017122661");
+        assert_eq!(body.twiml, Some(twiml));
+        assert!(body.url.is_none());
+    }
+
+    #[test]
+    fn create_call_with_url_is_setting_body() {
+        let url = "http://demo.twilio.com/docs/voice.xml";
+        let endpoint = CreateCall::with_url("AC123", "+15558675310", "+15017122661", url);
+
+        let RequestBody::Form(body) = endpoint.body else {
+            panic!("expected form body");
+        };
+        assert_eq!(body.url, Some(url));
+        assert!(body.twiml.is_none());
+    }
+
+    #[test]
+    fn create_call_body_with_twiml_is_accepting_twiml_at_limit() {
+        let twiml = "a".repeat(MAX_TWIML_BYTES);
+        let body = CreateCallBody {
+            to: "+15558675310",
+            from: "+15017122661",
+            ..Default::default()
+        }
+        .with_twiml(&twiml)
+        .unwrap();
+
+        assert_eq!(body.twiml.map(str::len), Some(MAX_TWIML_BYTES));
+    }
+
+    #[test]
+    fn create_call_body_with_twiml_is_erring_when_twiml_is_over_limit() {
+        let twiml = "a".repeat(MAX_TWIML_BYTES + 1);
+        let got = CreateCallBody::default().with_twiml(&twiml);
+
+        assert!(got.is_err());
+        if let Err(e) = got {
+            assert_eq!(
+                e.to_string(),
+                "inline TwiML is 4097 bytes, over the 4096 byte limit"
+            );
+        }
+    }
+}
@@ -2,12 +2,14 @@
 
 use super::*;
 use crate::endpoints::applications::ApiVersion;
-use crate::endpoints::voice::call::RecordingTrack;
+use crate::endpoints::voice::call::{RecordingTrack, Trim};
+use crate::error::TwilioError;
 use crate::url::query::{
     ByDateCreatedAndDateUpdated, ByFriendlyName, ConferenceQueryMarker, ParticipantQueryMarker,
 };
 use crate::TwilioQuery;
 use reqwest::RequestBuilder;
+use strum::Display;
 
 #[derive(Clone, Debug, Deserialize)]
 /// See [Conference Properties](https://www.twilio.com/docs/voice/api/conference-resource#conference-properties)
@@ -26,6 +28,22 @@ pub struct ConferenceResponse {
     pub call_sid_ending_conference: Option<String>,
 }
 
+/// A Twilio Region where conference audio is mixed.
+/// See [Global Infrastructure](https://www.twilio.com/docs/global-infrastructure/edge-locations#public-edge-locations)
+#[derive(Clone, Debug, Deserialize, Display, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum TwilioRegion {
+    Us1,
+    Us2,
+    Au1,
+    Br1,
+    De1,
+    Ie1,
+    Jp1,
+    Sg1,
+}
+
 #[derive(Clone, Debug)]
 /// See [Fetch Conference](https://www.twilio.com/docs/voice/api/conference-resource#fetch-a-conference-resource)
 pub struct FetchConference {
@@ -113,6 +131,8 @@ pub struct ListConferencesResponse {
     pub pagination: Pagination,
 }
 
+impl_paginated!(ListConferencesResponse, conferences, ConferenceResponse);
+
 #[derive(Debug)]
 /// See [Update A Conference Resource](https://www.twilio.com/docs/voice/api/conference-resource#update-a-conference-resource)
 pub struct UpdateConference<'a> {
@@ -171,6 +191,10 @@ impl TwilioEndpoint for UpdateConference<'_> {
 
 #[derive(Clone, Debug, Deserialize)]
 /// See [Participant Properties](https://www.twilio.com/docs/voice/api/conference-participant-resource#participant-properties)
+///
+/// Twilio doesn't return a participant's direction; it belongs to the underlying call
+/// leg, so fetch it with
+/// [`TwilioClientExt::participant_direction`](crate::TwilioClientExt::participant_direction).
 pub struct ParticipantResponse {
     pub account_sid: String,
     pub conference_sid: String,
@@ -184,11 +208,38 @@ pub struct ParticipantResponse {
     pub muted: Option<bool>,
     pub hold: Option<bool>,
     pub start_conference_on_enter: Option<bool>,
-    pub status: Option<String>,
+    pub status: Option<ParticipantStatus>,
     pub queue_time: Option<String>,
     pub uri: String,
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+/// See [Participant Status Values](https://www.twilio.com/docs/voice/api/conference-participant-resource#participant-status-values)
+#[serde(rename_all = "kebab-case")]
+pub enum ParticipantStatus {
+    Queued,
+    Connecting,
+    Ringing,
+    Connected,
+    Complete,
+    Failed,
+    /// A status added by Twilio after this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whether to record the conference the participant joins, see `ConferenceRecord` on
+/// [Create a Participant](https://www.twilio.com/docs/voice/api/conference-participant-resource#create-a-participant-resource).
+#[derive(Clone, Debug, Deserialize, Display, Serialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum ConferenceRecord {
+    True,
+    False,
+    RecordFromStart,
+    DoNotRecord,
+}
+
 #[derive(Debug)]
 pub struct CreateParticipant<'a> {
     pub account_sid: String,
@@ -232,9 +283,9 @@ pub struct CreateParticipantBody<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_participants: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conference_record: Option<&'a str>,
+    pub conference_record: Option<ConferenceRecord>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conference_trim: Option<&'a str>,
+    pub conference_trim: Option<Trim>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conference_status_callback: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -253,7 +304,7 @@ pub struct CreateParticipantBody<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sip_auth_password: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub region: Option<&'a str>,
+    pub region: Option<TwilioRegion>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conference_recording_status_callback: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -294,7 +345,7 @@ pub struct CreateParticipantBody<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amd_status_callback_method: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trim: Option<&'a str>,
+    pub trim: Option<Trim>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub call_token: Option<&'a str>,
 }
@@ -307,6 +358,31 @@ impl<'a> CreateParticipantBody<'a> {
             ..Default::default()
         }
     }
+
+    /// Sets `WaitUrl` to an empty string, so the participant waits in silence instead of
+    /// hearing Twilio's default hold music.
+    pub fn no_hold_music(mut self) -> Self {
+        self.wait_url = Some("");
+        self
+    }
+
+    /// Whether the conference hears the new leg's ringing or disconnect messages before it
+    /// answers. Twilio defaults this to true.
+    ///
+    /// The leg only joins, and `start_conference_on_enter` only applies, once it answers, so
+    /// early media is heard by whoever is already in a started conference.
+    pub fn with_early_media(mut self, early_media: bool) -> Self {
+        self.early_media = Some(early_media);
+        self
+    }
+
+    /// Forwards an inbound call into the conference, letting `from` be the original caller's
+    /// number even if it isn't one of yours. The token comes from the inbound call's webhook,
+    /// see [`TwilioRequestParams::call_token`](crate::request_parameters::TwilioRequestParams::call_token).
+    pub fn with_call_token(mut self, call_token: &'a str) -> Self {
+        self.call_token = Some(call_token);
+        self
+    }
     fn join_events<S>(events: &Vec<&'a str>, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -455,6 +531,31 @@ pub struct ListParticipantsResponse {
     pub pagination: Pagination,
 }
 
+impl_paginated!(ListParticipantsResponse, participants, ParticipantResponse);
+
+impl ListParticipantsResponse {
+    /// Errs with [`TwilioError::DuplicateLabel`] if a participant in this page already uses `label`.
+    ///
+    /// Twilio requires participant labels to be unique within a conference.
+    pub fn ensure_unique_label(&self, label: &str) -> Result<()> {
+        if self
+            .participants
+            .iter()
+            .any(|p| p.label.as_deref() == Some(label))
+        {
+            return Err(TwilioError::DuplicateLabel(label.to_string()));
+        }
+        Ok(())
+    }
+
+    /// The participants in this page that are coaching another, see `call_sid_to_coach`.
+    pub fn coaches(&self) -> impl Iterator<Item = &ParticipantResponse> {
+        self.participants
+            .iter()
+            .filter(|p| p.coaching == Some(true))
+    }
+}
+
 #[derive(Debug)]
 /// See [Update A Participant Resource](https://www.twilio.com/docs/voice/api/conference-participant-resource#update-a-participant-resource)
 pub struct UpdateParticipant<'a> {
@@ -578,3 +679,148 @@ impl TwilioEndpoint for DeleteParticipant {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGINATION: &str = r#""page": 0, "page_size": 50, "first_page_uri": "/first", "end": 1, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null"#;
+
+    fn participant_json(call_sid: &str, label: Option<&str>) -> String {
+        format!(
+            r#"{{"account_sid": "AC123", "conference_sid": "CF123", "call_sid": "{call_sid}", "label": {}, "uri": "/uri"}}"#,
+            label
+                .map(|l| format!(r#""{l}""#))
+                .unwrap_or("null".to_string())
+        )
+    }
+
+    #[test]
+    fn participant_response_is_deserializing_status() {
+        for (status, want) in [
+            ("connected", ParticipantStatus::Connected),
+            ("complete", ParticipantStatus::Complete),
+            ("on-a-break", ParticipantStatus::Unknown),
+        ] {
+            let json = format!(
+                r#"{{"account_sid": "AC123", "conference_sid": "CF123", "call_sid": "CA123", "status": "{status}", "uri": "/uri"}}"#
+            );
+            let participant: ParticipantResponse = serde_json::from_str(&json).unwrap();
+            assert_eq!(participant.status, Some(want));
+        }
+    }
+
+    #[test]
+    fn create_participant_body_no_hold_music_is_sending_empty_wait_url() {
+        let body = CreateParticipantBody::new("+15017122661", "+15558675310").no_hold_music();
+        let got = serde_urlencoded::to_string(&body).unwrap();
+
+        assert_eq!(got, "From=%2B15017122661&To=%2B15558675310&WaitUrl=");
+    }
+
+    #[test]
+    fn create_participant_body_is_serializing_conference_record() {
+        for (record, want) in [
+            (ConferenceRecord::True, "true"),
+            (ConferenceRecord::False, "false"),
+            (ConferenceRecord::RecordFromStart, "record-from-start"),
+            (ConferenceRecord::DoNotRecord, "do-not-record"),
+        ] {
+            let body = CreateParticipantBody {
+                conference_record: Some(record),
+                ..CreateParticipantBody::new("+15017122661", "+15558675310")
+            };
+            let got = serde_urlencoded::to_string(&body).unwrap();
+
+            assert_eq!(
+                got,
+                format!("From=%2B15017122661&To=%2B15558675310&ConferenceRecord={want}")
+            );
+        }
+    }
+
+    #[test]
+    fn create_participant_body_is_serializing_booleans_as_true_and_false() {
+        // Some(false) is sent rather than omitted, since beep and
+        // start_conference_on_enter default to true on Twilio's side
+        let body = CreateParticipantBody {
+            record: Some(true),
+            muted: Some(true),
+            beep: Some(false),
+            start_conference_on_enter: Some(false),
+            end_conference_on_exit: Some(true),
+            ..CreateParticipantBody::new("+15017122661", "+15558675310")
+        };
+        let got = serde_urlencoded::to_string(&body).unwrap();
+
+        assert_eq!(
+            got,
+            "From=%2B15017122661&To=%2B15558675310&Record=true&Muted=true&Beep=false&StartConferenceOnEnter=false&EndConferenceOnExit=true"
+        );
+    }
+
+    #[test]
+    fn create_participant_body_with_early_media_is_serializing_field() {
+        let body = CreateParticipantBody::new("+15017122661", "+15558675310");
+        assert!(!serde_urlencoded::to_string(&body)
+            .unwrap()
+            .contains("EarlyMedia"));
+
+        let got = serde_urlencoded::to_string(body.with_early_media(false)).unwrap();
+        assert_eq!(
+            got,
+            "From=%2B15017122661&To=%2B15558675310&EarlyMedia=false"
+        );
+    }
+
+    #[test]
+    fn create_participant_body_is_forwarding_call_token_from_webhook() {
+        use crate::request_parameters::TwilioRequestParams;
+
+        let webhook = "CallSid=CA123&AccountSid=AC123&From=%2B15558675310&To=%2B15017122661&CallStatus=ringing&ApiVersion=2010-04-01&Direction=inbound&CallToken=%7B%22parentCallInfoToken%22%3A%22eyJ%22%7D";
+        let params: TwilioRequestParams = serde_urlencoded::from_str(webhook).unwrap();
+        let call_token = params.call_token.as_deref().unwrap();
+        assert_eq!(call_token, r#"{"parentCallInfoToken":"eyJ"}"#);
+
+        let body =
+            CreateParticipantBody::new(&params.from, "+15551234567").with_call_token(call_token);
+        let got = serde_urlencoded::to_string(&body).unwrap();
+
+        assert_eq!(
+            got,
+            "From=%2B15558675310&To=%2B15551234567&CallToken=%7B%22parentCallInfoToken%22%3A%22eyJ%22%7D"
+        );
+    }
+
+    #[test]
+    fn ensure_unique_label_is_erring_when_label_already_exists() {
+        let json = format!(
+            r#"{{"participants": [{}, {}], {PAGINATION}}}"#,
+            participant_json("CA1", Some("customer")),
+            participant_json("CA2", None),
+        );
+        let resp: ListParticipantsResponse = serde_json::from_str(&json).unwrap();
+
+        let got = resp.ensure_unique_label("customer");
+        assert!(matches!(got, Err(TwilioError::DuplicateLabel(ref l)) if l == "customer"));
+        assert!(resp.ensure_unique_label("agent").is_ok());
+    }
+
+    #[test]
+    fn coaches_is_filtering_coaching_participants() {
+        let json = format!(
+            r#"{{"participants": [{}, {}, {}, {}], {PAGINATION}}}"#,
+            r#"{"account_sid": "AC123", "conference_sid": "CF123", "call_sid": "CA1", "coaching": true, "call_sid_to_coach": "CA2", "uri": "/uri"}"#,
+            r#"{"account_sid": "AC123", "conference_sid": "CF123", "call_sid": "CA2", "coaching": false, "uri": "/uri"}"#,
+            participant_json("CA3", None),
+            r#"{"account_sid": "AC123", "conference_sid": "CF123", "call_sid": "CA4", "coaching": true, "call_sid_to_coach": "CA3", "uri": "/uri"}"#,
+        );
+        let resp: ListParticipantsResponse = serde_json::from_str(&json).unwrap();
+
+        let coaches: Vec<_> = resp
+            .coaches()
+            .map(|p| (p.call_sid.as_str(), p.call_sid_to_coach.as_deref()))
+            .collect();
+        assert_eq!(coaches, vec![("CA1", Some("CA2")), ("CA4", Some("CA3"))]);
+    }
+}
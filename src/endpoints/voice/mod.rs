@@ -1,4 +1,9 @@
 use super::*;
 pub mod call;
-pub mod stream;
 pub mod conference;
+pub mod queue;
+pub mod recording;
+pub mod recording_settings;
+pub mod stream;
+pub mod transcription;
+pub mod user_defined_message;
@@ -0,0 +1,468 @@
+//! Queue and Member endpoints
+//! See [Queue resource reference](https://www.twilio.com/docs/voice/api/queue-resource)
+use super::*;
+use crate::url::query::TwilioQuery;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Queue Properties](https://www.twilio.com/docs/voice/api/queue-resource#queue-properties)
+pub struct QueueResponse {
+    /// The SID of the Account that created this Queue resource.
+    pub account_sid: String,
+    /// The average wait time in seconds of the members in this queue, based on the current members.
+    pub average_wait_time: Option<u32>,
+    /// The number of calls currently in the queue.
+    pub current_size: Option<u32>,
+    /// The date and time in GMT that this resource was created specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT that this resource was last updated, specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// A string that you assigned to describe this resource.
+    pub friendly_name: Option<String>,
+    /// The maximum number of calls that can be in the queue. The default is 1000 and the maximum is 5000.
+    pub max_size: Option<u32>,
+    /// The unique string that that we created to identify this Queue resource.
+    pub sid: String,
+    /// The URI of this resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+}
+
+#[derive(Debug)]
+pub struct CreateQueue<'a> {
+    pub account_sid: String,
+    pub body: RequestBody<CreateQueueBody<'a>>,
+}
+
+impl<'a> CreateQueue<'a> {
+    pub fn new(account_sid: impl Into<String>, body: CreateQueueBody<'a>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateQueueBody<'a> {
+    /// Must be unique within the account, up to 64 characters.
+    pub friendly_name: &'a str,
+    /// At most 5000; Twilio defaults to 1000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<u32>,
+}
+
+impl<'a> CreateQueueBody<'a> {
+    pub fn new(friendly_name: &'a str) -> Self {
+        Self {
+            friendly_name,
+            max_size: None,
+        }
+    }
+}
+
+impl TwilioEndpoint for CreateQueue<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Queues.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = QueueResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchQueue {
+    pub account_sid: String,
+    pub queue_sid: String,
+}
+
+impl FetchQueue {
+    pub fn new(account_sid: impl Into<String>, queue_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            queue_sid: queue_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchQueue {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Queues/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = QueueResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.queue_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListQueues {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListQueues {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListQueues {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Queues.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListQueuesResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListQueuesResponse {
+    pub queues: Vec<QueueResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListQueuesResponse, queues, QueueResponse);
+
+#[derive(Debug)]
+pub struct UpdateQueue<'a> {
+    pub account_sid: String,
+    pub queue_sid: String,
+    pub body: RequestBody<UpdateQueueBody<'a>>,
+}
+
+impl<'a> UpdateQueue<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        queue_sid: impl Into<String>,
+        body: UpdateQueueBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            queue_sid: queue_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateQueueBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<u32>,
+}
+
+impl TwilioEndpoint for UpdateQueue<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Queues/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = QueueResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.queue_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteQueue {
+    pub account_sid: String,
+    pub queue_sid: String,
+}
+
+impl DeleteQueue {
+    pub fn new(account_sid: impl Into<String>, queue_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            queue_sid: queue_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteQueue {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Queues/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.queue_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Member Properties](https://www.twilio.com/docs/voice/api/member-resource#member-properties)
+pub struct MemberResponse {
+    /// The SID of the Call the Member resource is associated with.
+    pub call_sid: String,
+    /// The date that the member was enqueued, given in RFC 2822 format.
+    pub date_enqueued: Option<String>,
+    /// This member's current position in the queue.
+    pub position: Option<u32>,
+    /// The URI of the resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+    /// The number of seconds the member has been in the queue.
+    pub wait_time: Option<u32>,
+    /// The SID of the Queue the member is in.
+    pub queue_sid: Option<String>,
+}
+
+/// Fetches one member of a queue.
+///
+/// `call_sid` may also be `Front` for whichever call has waited longest.
+#[derive(Clone, Debug)]
+pub struct FetchMember {
+    pub account_sid: String,
+    pub queue_sid: String,
+    pub call_sid: String,
+}
+
+impl FetchMember {
+    pub fn new(
+        account_sid: impl Into<String>,
+        queue_sid: impl Into<String>,
+        call_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            queue_sid: queue_sid.into(),
+            call_sid: call_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchMember {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Queues/{QueueSid}/Members/{CallSid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = MemberResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{QueueSid}", &self.queue_sid),
+            ("{CallSid}", &self.call_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListMembers {
+    pub account_sid: String,
+    pub queue_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListMembers {
+    pub fn new(
+        account_sid: impl Into<String>,
+        queue_sid: impl Into<String>,
+        query: TwilioQuery<Self>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            queue_sid: queue_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListMembers {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Queues/{QueueSid}/Members.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListMembersResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{QueueSid}", &self.queue_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListMembersResponse {
+    pub queue_members: Vec<MemberResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListMembersResponse, queue_members, MemberResponse);
+
+/// Dequeues a member, redirecting its call to the TwiML at `url`.
+///
+/// `call_sid` may also be `Front` to dequeue whichever call has waited longest.
+#[derive(Debug)]
+pub struct UpdateMember<'a> {
+    pub account_sid: String,
+    pub queue_sid: String,
+    pub call_sid: String,
+    pub body: RequestBody<UpdateMemberBody<'a>>,
+}
+
+impl<'a> UpdateMember<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        queue_sid: impl Into<String>,
+        call_sid: impl Into<String>,
+        body: UpdateMemberBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            queue_sid: queue_sid.into(),
+            call_sid: call_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateMemberBody<'a> {
+    /// The absolute URL of the TwiML the dequeued call should execute.
+    pub url: &'a str,
+    /// `GET` or `POST`; Twilio defaults to `POST`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<&'a str>,
+}
+
+impl<'a> UpdateMemberBody<'a> {
+    pub fn new(url: &'a str) -> Self {
+        Self { url, method: None }
+    }
+}
+
+impl TwilioEndpoint for UpdateMember<'_> {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Queues/{QueueSid}/Members/{CallSid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = MemberResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{QueueSid}", &self.queue_sid),
+            ("{CallSid}", &self.call_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn queue_response_is_deserializing_size_and_wait_time() {
+        let json = r#"{"account_sid": "AC123", "average_wait_time": 12, "current_size": 3, "date_created": "Tue, 04 Aug 2015 18:39:09 +0000", "date_updated": "Tue, 04 Aug 2015 18:39:09 +0000", "friendly_name": "support", "max_size": 100, "sid": "QU123", "uri": "/2010-04-01/Accounts/AC123/Queues/QU123.json"}"#;
+        let queue: QueueResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(queue.sid, "QU123");
+        assert_eq!(queue.current_size, Some(3));
+        assert_eq!(queue.average_wait_time, Some(12));
+    }
+
+    #[test]
+    fn list_members_response_is_paginated() {
+        let json = r#"{"queue_members": [{"call_sid": "CA123", "date_enqueued": "Tue, 07 Aug 2012 22:57:41 +0000", "position": 1, "uri": "/uri", "wait_time": 143, "queue_sid": "QU123"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListMembersResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(resp.queue_members[0].position, Some(1));
+    }
+
+    #[test]
+    fn update_member_is_dequeuing_front_of_queue() {
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let body = UpdateMemberBody::new("https://example.com/agent.xml");
+        let got = serde_urlencoded::to_string(&body).unwrap();
+        let url = UpdateMember::new("AC123", "QU123", "Front", body).url(&base_url);
+
+        assert_eq!(
+            url.path(),
+            "/2010-04-01/Accounts/AC123/Queues/QU123/Members/Front.json"
+        );
+        assert_eq!(got, "Url=https%3A%2F%2Fexample.com%2Fagent.xml");
+    }
+}
@@ -0,0 +1,621 @@
+//! Recording endpoints
+//! See [Recording resource reference](https://www.twilio.com/docs/voice/api/recording)
+use super::*;
+use crate::endpoints::applications::ApiVersion;
+use crate::endpoints::voice::call::{RecordingStatusCallbackEvent, RecordingTrack, Trim};
+use crate::url::query::{ByDateCreatedRange, RecordingQueryMarker, TwilioQuery};
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Recording Properties](https://www.twilio.com/docs/voice/api/recording#recording-properties)
+pub struct RecordingResponse {
+    /// The SID of the Account that created the Recording resource.
+    pub account_sid: String,
+    /// The API version used during the recording.
+    pub api_version: Option<ApiVersion>,
+    /// The SID of the Call the Recording resource is associated with.
+    pub call_sid: Option<String>,
+    /// The Conference SID that identifies the conference associated with the recording, if any.
+    pub conference_sid: Option<String>,
+    /// The date and time in GMT that the resource was created specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT that the resource was last updated specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// The start time of the recording in GMT and in RFC 2822 format.
+    pub start_time: Option<String>,
+    /// The length of the recording in seconds.
+    pub duration: Option<String>,
+    /// The unique string that we created to identify the Recording resource.
+    pub sid: String,
+    /// The one-time cost of creating the recording in the `price_unit` currency.
+    pub price: Option<String>,
+    /// The currency used in the `price` property, e.g. `USD`.
+    pub price_unit: Option<String>,
+    /// The status of the recording. Can be: processing, completed, absent or deleted.
+    pub status: Option<String>,
+    /// The number of channels in the final recording file, 1 or 2.
+    pub channels: Option<u32>,
+    /// How the recording was created, e.g. `DialVerb`, `Conference`, `OutboundAPI`.
+    pub source: Option<String>,
+    /// The error code that describes why the recording is `absent`.
+    pub error_code: Option<u32>,
+    /// The URI of the resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+    /// The URL of the media file associated with this recording resource.
+    pub media_url: Option<String>,
+}
+
+impl ByDateCreatedRange for ListRecordings {}
+impl RecordingQueryMarker for ListRecordings {}
+
+/// Lists the account's recordings across every call and conference.
+#[derive(Clone, Debug)]
+pub struct ListRecordings {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListRecordings {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListRecordings {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Recordings.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListRecordingsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListRecordingsResponse {
+    pub recordings: Vec<RecordingResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(ListRecordingsResponse, recordings, RecordingResponse);
+
+#[derive(Clone, Debug)]
+pub struct FetchRecording {
+    pub account_sid: String,
+    pub recording_sid: String,
+}
+
+impl FetchRecording {
+    pub fn new(account_sid: impl Into<String>, recording_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            recording_sid: recording_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchRecording {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Recordings/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = RecordingResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.recording_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteRecording {
+    pub account_sid: String,
+    pub recording_sid: String,
+}
+
+impl DeleteRecording {
+    pub fn new(account_sid: impl Into<String>, recording_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            recording_sid: recording_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteRecording {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Recordings/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.recording_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+/// The audio formats a recording can be downloaded in.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RecordingFormat {
+    /// Uncompressed, as recorded.
+    #[default]
+    Wav,
+    Mp3,
+}
+
+impl RecordingFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingFormat::Wav => "wav",
+            RecordingFormat::Mp3 => "mp3",
+        }
+    }
+}
+
+/// Downloads the recording's audio rather than its metadata.
+///
+/// The recording URI with a `.wav` or `.mp3` extension serves the file in that format.
+#[derive(Clone, Debug)]
+pub struct DownloadRecordingMedia {
+    pub account_sid: String,
+    pub recording_sid: String,
+    pub format: RecordingFormat,
+}
+
+impl DownloadRecordingMedia {
+    pub fn new(
+        account_sid: impl Into<String>,
+        recording_sid: impl Into<String>,
+        format: RecordingFormat,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            recording_sid: recording_sid.into(),
+            format,
+        }
+    }
+}
+
+impl TwilioEndpoint for DownloadRecordingMedia {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Recordings/{Sid}.{Format}";
+
+    const METHOD: Method = Method::GET;
+
+    const ACCEPT: &'static str = "*/*";
+
+    type ResponseBody = Vec<u8>;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.recording_sid),
+            ("{Format}", self.format.as_str()),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.bytes().await?.to_vec())
+    }
+}
+
+/// Starts recording a call that is already in progress.
+#[derive(Debug)]
+pub struct CreateCallRecording<'a> {
+    pub account_sid: String,
+    pub call_sid: String,
+    pub body: RequestBody<CreateCallRecordingBody<'a>>,
+}
+
+impl<'a> CreateCallRecording<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        call_sid: impl Into<String>,
+        body: CreateCallRecordingBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            call_sid: call_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateCallRecordingBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_status_callback: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_status_callback_method: Option<&'a str>,
+    #[serde(
+        rename = "RecordingStatusCallbackEvent",
+        serialize_with = "RecordingStatusCallbackEvent::serialize_in_progress",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub recording_status_callback_event_in_progress: Option<bool>,
+    #[serde(
+        rename = "RecordingStatusCallbackEvent",
+        serialize_with = "RecordingStatusCallbackEvent::serialize_completed",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub recording_status_callback_event_completed: Option<bool>,
+    #[serde(
+        rename = "RecordingStatusCallbackEvent",
+        serialize_with = "RecordingStatusCallbackEvent::serialize_absent",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub recording_status_callback_event_absent: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trim: Option<Trim>,
+    /// `mono` or `dual`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_channels: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_track: Option<RecordingTrack>,
+}
+
+impl CreateCallRecordingBody<'_> {
+    /// Subscribes `recording_status_callback` to exactly `events`.
+    pub fn with_recording_events(mut self, events: &[RecordingStatusCallbackEvent]) -> Self {
+        [
+            self.recording_status_callback_event_in_progress,
+            self.recording_status_callback_event_completed,
+            self.recording_status_callback_event_absent,
+        ] = RecordingStatusCallbackEvent::flags(events);
+        self
+    }
+}
+
+impl TwilioEndpoint for CreateCallRecording<'_> {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Calls/{CallSid}/Recordings.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = RecordingResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CallSid}", &self.call_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+/// The states a live call recording can be moved to.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CallRecordingStatus {
+    Paused,
+    /// Resumes a paused recording.
+    InProgress,
+    Stopped,
+}
+
+/// What a paused stretch of the call becomes in the recording.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PauseBehavior {
+    /// Leaves the paused stretch out entirely.
+    Skip,
+    /// Replaces the paused stretch with silence. Twilio's default.
+    #[default]
+    Silence,
+}
+
+/// Pauses, resumes or stops a live call recording.
+///
+/// `recording_sid` may also be `Twilio.CURRENT` for the call's only active recording.
+#[derive(Debug)]
+pub struct UpdateCallRecording {
+    pub account_sid: String,
+    pub call_sid: String,
+    pub recording_sid: String,
+    pub body: RequestBody<UpdateCallRecordingBody>,
+}
+
+impl UpdateCallRecording {
+    pub fn new(
+        account_sid: impl Into<String>,
+        call_sid: impl Into<String>,
+        recording_sid: impl Into<String>,
+        body: UpdateCallRecordingBody,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            call_sid: call_sid.into(),
+            recording_sid: recording_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateCallRecordingBody {
+    pub status: CallRecordingStatus,
+    /// Only applies when pausing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pause_behavior: Option<PauseBehavior>,
+}
+
+impl UpdateCallRecordingBody {
+    pub fn pause(pause_behavior: PauseBehavior) -> Self {
+        Self {
+            status: CallRecordingStatus::Paused,
+            pause_behavior: Some(pause_behavior),
+        }
+    }
+
+    pub fn resume() -> Self {
+        Self {
+            status: CallRecordingStatus::InProgress,
+            pause_behavior: None,
+        }
+    }
+
+    pub fn stop() -> Self {
+        Self {
+            status: CallRecordingStatus::Stopped,
+            pause_behavior: None,
+        }
+    }
+}
+
+impl TwilioEndpoint for UpdateCallRecording {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Calls/{CallSid}/Recordings/{Sid}.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = RecordingResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CallSid}", &self.call_sid),
+            ("{Sid}", &self.recording_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+impl ByDateCreatedRange for ListCallRecordings {}
+
+/// Lists the recordings of one call.
+#[derive(Clone, Debug)]
+pub struct ListCallRecordings {
+    pub account_sid: String,
+    pub call_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListCallRecordings {
+    pub fn new(
+        account_sid: impl Into<String>,
+        call_sid: impl Into<String>,
+        query: TwilioQuery<Self>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            call_sid: call_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListCallRecordings {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Calls/{CallSid}/Recordings.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListRecordingsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CallSid}", &self.call_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteCallRecording {
+    pub account_sid: String,
+    pub call_sid: String,
+    pub recording_sid: String,
+}
+
+impl DeleteCallRecording {
+    pub fn new(
+        account_sid: impl Into<String>,
+        call_sid: impl Into<String>,
+        recording_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            call_sid: call_sid.into(),
+            recording_sid: recording_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteCallRecording {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Calls/{CallSid}/Recordings/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CallSid}", &self.call_sid),
+            ("{Sid}", &self.recording_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn list_recordings_is_querying_date_range() {
+        let query = TwilioQuery::new()
+            .with_date_created_after("2024-01-01")
+            .with_date_created_before("2024-01-31")
+            .with_page_size(100);
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = ListRecordings::new("AC123", query).url(&base_url);
+
+        assert_eq!(
+            url.query_pairs().into_owned().collect::<Vec<_>>(),
+            vec![
+                ("DateCreated>".to_string(), "2024-01-01".to_string()),
+                ("DateCreated<".to_string(), "2024-01-31".to_string()),
+                ("PageSize".to_string(), "100".to_string()),
+            ]
+        );
+        assert_eq!(url.path(), "/2010-04-01/Accounts/AC123/Recordings.json");
+    }
+
+    #[test]
+    fn list_recordings_is_querying_call_and_conference() {
+        let query: TwilioQuery<ListRecordings> = TwilioQuery::new()
+            .with_call_sid("CA123")
+            .with_conference_sid("CF123")
+            .with_date_created_on("2024-01-15");
+
+        assert_eq!(
+            query.params,
+            vec![
+                ("CallSid", "CA123".to_string()),
+                ("ConferenceSid", "CF123".to_string()),
+                ("DateCreated", "2024-01-15".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn download_recording_media_is_using_format_extension() {
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url =
+            DownloadRecordingMedia::new("AC123", "RE123", RecordingFormat::Mp3).url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/Recordings/RE123.mp3"
+        );
+    }
+
+    #[test]
+    fn list_recordings_response_is_deserializing() {
+        let json = r#"{"recordings": [{"account_sid": "AC123", "call_sid": "CA123", "conference_sid": null, "sid": "RE123", "status": "completed", "channels": 2, "source": "OutboundAPI", "duration": "4", "uri": "/2010-04-01/Accounts/AC123/Recordings/RE123.json"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": "/next", "previous_page_uri": null}"#;
+        let resp: ListRecordingsResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        assert_eq!(resp.recordings[0].sid, "RE123");
+        assert_eq!(resp.recordings[0].channels, Some(2));
+        assert!(resp.recordings[0].conference_sid.is_none());
+    }
+
+    #[test]
+    fn create_call_recording_body_is_repeating_recording_events() {
+        let body = CreateCallRecordingBody {
+            recording_status_callback: Some("https://example.com/recording"),
+            ..Default::default()
+        }
+        .with_recording_events(&[
+            RecordingStatusCallbackEvent::InProgress,
+            RecordingStatusCallbackEvent::Completed,
+        ]);
+        let got = serde_urlencoded::to_string(&body).unwrap();
+
+        assert_eq!(
+            got,
+            "RecordingStatusCallback=https%3A%2F%2Fexample.com%2Frecording&RecordingStatusCallbackEvent=in-progress&RecordingStatusCallbackEvent=completed"
+        );
+    }
+
+    #[test]
+    fn update_call_recording_body_is_pausing_with_behavior() {
+        let got = serde_urlencoded::to_string(UpdateCallRecordingBody::pause(PauseBehavior::Skip))
+            .unwrap();
+        assert_eq!(got, "Status=paused&PauseBehavior=skip");
+
+        let got = serde_urlencoded::to_string(UpdateCallRecordingBody::resume()).unwrap();
+        assert_eq!(got, "Status=in-progress");
+    }
+
+    #[test]
+    fn update_call_recording_is_addressing_current_recording() {
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = UpdateCallRecording::new(
+            "AC123",
+            "CA123",
+            "Twilio.CURRENT",
+            UpdateCallRecordingBody::stop(),
+        )
+        .url(&base_url);
+
+        assert_eq!(
+            url.path(),
+            "/2010-04-01/Accounts/AC123/Calls/CA123/Recordings/Twilio.CURRENT.json"
+        );
+    }
+}
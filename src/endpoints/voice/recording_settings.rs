@@ -0,0 +1,142 @@
+//! Recording Settings endpoints
+//! See [Voice Recording Settings](https://www.twilio.com/docs/voice/api/recording-settings)
+//!
+//! The settings apply to every recording the account makes, so there is nothing to
+//! identify beyond the credentials the request is made with.
+use super::*;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Recording Settings Properties](https://www.twilio.com/docs/voice/api/recording-settings#recording-settings-properties)
+pub struct RecordingSettingsResponse {
+    /// The SID of the Account the settings apply to.
+    pub account_sid: String,
+    /// The string that you assigned to describe the settings.
+    pub friendly_name: Option<String>,
+    /// The SID of the AWS Credential recordings are stored with, when external storage is on.
+    pub aws_credentials_sid: Option<String>,
+    /// The S3 bucket URL recordings are stored in, when external storage is on.
+    pub aws_s3_url: Option<String>,
+    /// Whether recordings are stored in `aws_s3_url` rather than by Twilio.
+    pub aws_storage_enabled: Option<bool>,
+    /// The SID of the Public Key recordings are encrypted with.
+    pub encryption_key_sid: Option<String>,
+    /// Whether recordings are encrypted with `encryption_key_sid`.
+    pub encryption_enabled: Option<bool>,
+    /// The absolute URL of the resource.
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FetchRecordingSettings;
+
+impl FetchRecordingSettings {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TwilioEndpoint for FetchRecordingSettings {
+    const PATH: &'static str = "/v1/RecordingSettings";
+
+    const METHOD: Method = Method::GET;
+
+    const SUBDOMAIN: &'static str = "voice";
+
+    type ResponseBody = RecordingSettingsResponse;
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateRecordingSettings<'a> {
+    pub body: RequestBody<UpdateRecordingSettingsBody<'a>>,
+}
+
+impl<'a> UpdateRecordingSettings<'a> {
+    pub fn new(body: UpdateRecordingSettingsBody<'a>) -> Self {
+        Self {
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateRecordingSettingsBody<'a> {
+    pub friendly_name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_credentials_sid: Option<&'a str>,
+    #[serde(rename = "AwsS3Url", skip_serializing_if = "Option::is_none")]
+    pub aws_s3_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_storage_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_key_sid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_enabled: Option<bool>,
+}
+
+impl<'a> UpdateRecordingSettingsBody<'a> {
+    pub fn new(friendly_name: &'a str) -> Self {
+        Self {
+            friendly_name,
+            ..Default::default()
+        }
+    }
+
+    /// Encrypts every new recording with the Public Key `encryption_key_sid`.
+    pub fn with_encryption(mut self, encryption_key_sid: &'a str) -> Self {
+        self.encryption_key_sid = Some(encryption_key_sid);
+        self.encryption_enabled = Some(true);
+        self
+    }
+}
+
+impl TwilioEndpoint for UpdateRecordingSettings<'_> {
+    const PATH: &'static str = "/v1/RecordingSettings";
+
+    const METHOD: Method = Method::POST;
+
+    const SUBDOMAIN: &'static str = "voice";
+
+    type ResponseBody = RecordingSettingsResponse;
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recording_settings_response_is_deserializing() {
+        let json = r#"{"account_sid": "AC123", "friendly_name": "Compliance", "aws_credentials_sid": null, "aws_s3_url": null, "aws_storage_enabled": false, "encryption_key_sid": "CR123", "encryption_enabled": true, "url": "https://voice.twilio.com/v1/RecordingSettings"}"#;
+        let settings: RecordingSettingsResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(settings.account_sid, "AC123");
+        assert_eq!(settings.friendly_name.as_deref(), Some("Compliance"));
+        assert_eq!(settings.encryption_key_sid.as_deref(), Some("CR123"));
+        assert_eq!(settings.encryption_enabled, Some(true));
+        assert_eq!(settings.aws_storage_enabled, Some(false));
+    }
+
+    #[test]
+    fn update_recording_settings_is_targeting_voice_api() {
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url = UpdateRecordingSettings::new(UpdateRecordingSettingsBody::new("Compliance"))
+            .url(&base_url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://voice.twilio.com/v1/RecordingSettings"
+        );
+    }
+}
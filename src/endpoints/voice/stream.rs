@@ -3,6 +3,10 @@
 
 use super::*;
 use crate::error::TwilioError;
+use strum::Display;
+use validator::Validate;
+
+pub use crate::twiml::voice::Track as StreamTrack;
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct StreamResponse {
@@ -22,11 +26,14 @@ pub struct StreamResponse {
     pub uri: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "kebab-case")]
 pub enum StreamStatus {
     InProgress,
     Stopped,
+    /// A status added by Twilio after this version of the crate.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug)]
@@ -50,10 +57,13 @@ impl<'a> CreateStream<'a> {
     }
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct CreateStreamBody<'a> {
     pub url: &'a str,
+    /// A name for the Stream, unique per call. The name can be used in place of the
+    /// stream SID to stop the Stream via [`UpdateStream`].
+    #[validate(length(min = 1))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -74,6 +84,16 @@ impl<'a> CreateStreamBody<'a> {
             status_callback_method: None,
         }
     }
+
+    pub fn with_track(mut self, track: &'a str) -> Self {
+        self.track = Some(track);
+        self
+    }
+
+    pub fn with_track_typed(mut self, track: StreamTrack) -> Self {
+        self.track = Some(track.as_str());
+        self
+    }
 }
 
 impl TwilioEndpoint for CreateStream<'_> {
@@ -94,6 +114,9 @@ impl TwilioEndpoint for CreateStream<'_> {
     where
         Self: Sized,
     {
+        if let RequestBody::Form(body) | RequestBody::Json(body) = &self.body {
+            body.validate()?;
+        }
         self.body.configure(builder)
     }
 
@@ -109,6 +132,7 @@ impl TwilioEndpoint for CreateStream<'_> {
 pub struct UpdateStream {
     pub account_sid: String,
     pub call_sid: String,
+    /// The SID of the Stream, or the `name` it was created with.
     pub stream_sid: String,
 }
 
@@ -167,6 +191,35 @@ pub enum TwilioMessage {
     Dtmf(DtmfMessage),
 }
 
+impl TwilioMessage {
+    /// The `event` name of the message, e.g. `media`.
+    pub fn event(&self) -> &str {
+        match self {
+            TwilioMessage::Connected(msg) => &msg.event,
+            TwilioMessage::Start(msg) => &msg.event,
+            TwilioMessage::Media(msg) => &msg.event,
+            TwilioMessage::Mark(msg) => &msg.event,
+            TwilioMessage::Stop(msg) => &msg.event,
+            TwilioMessage::Dtmf(msg) => &msg.event,
+        }
+    }
+
+    /// The parsed `sequenceNumber` of the message, if it carries one.
+    ///
+    /// Twilio numbers every message after `connected` in order, so a gap or decrease
+    /// between consecutive messages means a frame was dropped or arrived out of order.
+    pub fn sequence(&self) -> Option<u64> {
+        match self {
+            TwilioMessage::Connected(_) => None,
+            TwilioMessage::Start(msg) => msg.sequence(),
+            TwilioMessage::Media(msg) => msg.sequence(),
+            TwilioMessage::Mark(msg) => msg.sequence(),
+            TwilioMessage::Stop(msg) => msg.sequence(),
+            TwilioMessage::Dtmf(msg) => msg.sequence(),
+        }
+    }
+}
+
 impl TryFrom<&str> for TwilioMessage {
     type Error = TwilioError;
 
@@ -175,6 +228,67 @@ impl TryFrom<&str> for TwilioMessage {
     }
 }
 
+/// Tracks the lifecycle of a single media stream websocket.
+///
+/// Twilio sends `connected`, then `start`, then any number of `media`, `mark` and `dtmf`
+/// messages, and finally `stop`. A dropped stream cannot be resumed, so once the session
+/// is stopped any per-stream resources should be cleaned up.
+#[derive(Clone, Debug, Default)]
+pub struct StreamSession {
+    pub state: StreamSessionState,
+    pub stream_sid: Option<String>,
+    pub call_sid: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum StreamSessionState {
+    #[default]
+    Pending,
+    Connected,
+    Started,
+    Stopped,
+}
+
+impl StreamSession {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_stopped(msg: &TwilioMessage) -> bool {
+        matches!(msg, TwilioMessage::Stop(_))
+    }
+
+    /// Advances the session with the next message from Twilio, erring with
+    /// [`TwilioError::UnexpectedStreamMessage`] if it arrives out of lifecycle order.
+    pub fn handle(&mut self, msg: &TwilioMessage) -> Result<StreamSessionState> {
+        use StreamSessionState::*;
+
+        let next = match (self.state, msg) {
+            (Pending, TwilioMessage::Connected(_)) => Connected,
+            (Connected, TwilioMessage::Start(start)) => {
+                self.stream_sid = Some(start.stream_sid.clone());
+                self.call_sid = Some(start.start.call_sid.clone());
+                Started
+            }
+            (
+                Started,
+                TwilioMessage::Media(_) | TwilioMessage::Mark(_) | TwilioMessage::Dtmf(_),
+            ) => Started,
+            (Started, TwilioMessage::Stop(_)) => Stopped,
+            (state, msg) => {
+                return Err(TwilioError::UnexpectedStreamMessage {
+                    state,
+                    event: msg.event().to_string(),
+                })
+            }
+        };
+
+        self.state = next;
+        Ok(next)
+    }
+}
+
 /// See [Connected Message](https://www.twilio.com/docs/voice/media-streams/websocket-messages#connected-message)
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -194,6 +308,12 @@ pub struct StartMessage {
     pub stream_sid: String,
 }
 
+impl StartMessage {
+    pub fn sequence(&self) -> Option<u64> {
+        self.sequence_number.parse().ok()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct StartMetadata {
@@ -213,6 +333,37 @@ pub struct MediaFormat {
     pub channels: u32,
 }
 
+impl MediaFormat {
+    /// Whether the audio has two interleaved channels, as a dual-channel recorded call's does.
+    pub fn is_stereo(&self) -> bool {
+        self.channels == 2
+    }
+
+    /// Decodes `audio` from 8-bit μ-law, the only encoding Twilio streams, into one
+    /// Vec of 16-bit PCM samples for each channel, deinterleaving stereo audio.
+    pub fn decode(&self, audio: &[u8]) -> Vec<Vec<i16>> {
+        let channels = self.channels.max(1) as usize;
+        let mut decoded = vec![Vec::with_capacity(audio.len() / channels); channels];
+        for (i, &byte) in audio.iter().enumerate() {
+            decoded[i % channels].push(mulaw_to_linear(byte));
+        }
+        decoded
+    }
+}
+
+/// Expands a G.711 μ-law byte into a 16-bit linear PCM sample.
+fn mulaw_to_linear(byte: u8) -> i16 {
+    let byte = !byte;
+    let exponent = (byte >> 4) & 0x07;
+    let mantissa = (byte & 0x0F) as i16;
+    let magnitude = (((mantissa << 3) + 0x84) << exponent) - 0x84;
+    if byte & 0x80 != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum Track {
     #[serde(rename = "inbound")]
@@ -244,6 +395,14 @@ pub struct Media {
     pub timestamp: Option<String>,
 }
 
+impl Media {
+    /// The raw audio bytes of the base64 `payload`, see [`MediaFormat::decode`].
+    pub fn decode_payload(&self) -> Result<Vec<u8>> {
+        use base64::Engine;
+        Ok(base64::engine::general_purpose::STANDARD.decode(&self.payload)?)
+    }
+}
+
 impl MediaMessage {
     pub fn new(stream_sid: impl Into<String>, payload: impl Into<String>) -> Self {
         MediaMessage {
@@ -256,6 +415,10 @@ impl MediaMessage {
             },
         }
     }
+
+    pub fn sequence(&self) -> Option<u64> {
+        self.sequence_number.as_deref().and_then(|s| s.parse().ok())
+    }
 }
 
 /// See [Stop Message](https://www.twilio.com/docs/voice/media-streams/websocket-messages#stop-message)
@@ -268,6 +431,12 @@ pub struct StopMessage {
     pub stop: Stop,
 }
 
+impl StopMessage {
+    pub fn sequence(&self) -> Option<u64> {
+        self.sequence_number.parse().ok()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Stop {
@@ -285,6 +454,12 @@ pub struct DtmfMessage {
     pub dtmf: Dtmf,
 }
 
+impl DtmfMessage {
+    pub fn sequence(&self) -> Option<u64> {
+        self.sequence_number.parse().ok()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Dtmf {
@@ -303,6 +478,12 @@ pub struct MarkMessage {
     pub mark: Mark,
 }
 
+impl MarkMessage {
+    pub fn sequence(&self) -> Option<u64> {
+        self.sequence_number.as_deref().and_then(|s| s.parse().ok())
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Mark {
@@ -325,3 +506,171 @@ impl ClearMessage {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stream_response_is_deserializing_status() {
+        for (status, want) in [
+            ("in-progress", StreamStatus::InProgress),
+            ("stopped", StreamStatus::Stopped),
+            ("paused", StreamStatus::Unknown),
+        ] {
+            let json = format!(
+                r#"{{"sid": "MZ123", "account_sid": "AC123", "call_sid": "CA123", "name": null, "status": "{status}", "date_updated": null, "uri": "/uri"}}"#
+            );
+            let stream: StreamResponse = serde_json::from_str(&json).unwrap();
+            assert_eq!(stream.status, want);
+        }
+    }
+
+    #[test]
+    fn create_stream_is_erring_when_name_is_empty() {
+        let body = CreateStreamBody {
+            name: Some(""),
+            ..CreateStreamBody::new("wss://example.com/stream")
+        };
+        assert!(body.validate().is_err());
+
+        let builder = reqwest::Client::new().post("https://api.twilio.com");
+        let got = CreateStream::new("AC123", "CA123", body).configure_request_body(builder);
+        assert!(matches!(got, Err(TwilioError::Validator(_))));
+    }
+
+    #[test]
+    fn create_stream_body_with_track_typed_is_serializing_track() {
+        for (track, want) in [
+            (StreamTrack::InboundTrack, "inbound_track"),
+            (StreamTrack::OutboundTrack, "outbound_track"),
+            (StreamTrack::BothTracks, "both_tracks"),
+        ] {
+            let body = CreateStreamBody::new("wss://example.com/stream").with_track_typed(track);
+            let got = serde_urlencoded::to_string(&body).unwrap();
+            assert_eq!(
+                got,
+                format!("Url=wss%3A%2F%2Fexample.com%2Fstream&Track={want}")
+            );
+        }
+    }
+
+    #[test]
+    fn create_stream_is_accepting_named_and_unnamed_streams() {
+        let unnamed = CreateStreamBody::new("wss://example.com/stream");
+        let named = CreateStreamBody {
+            name: Some("my-stream"),
+            ..unnamed.clone()
+        };
+
+        assert!(unnamed.validate().is_ok());
+        assert!(named.validate().is_ok());
+    }
+
+    #[test]
+    fn media_format_is_deinterleaving_stereo_audio() {
+        let start = START.replace(r#""channels": 1"#, r#""channels": 2"#);
+        let TwilioMessage::Start(start) = serde_json::from_str(&start).unwrap() else {
+            panic!("expected a start message");
+        };
+        let format = start.start.media_format;
+        assert!(format.is_stereo());
+
+        let media = MediaMessage::new("MZ123", "/wCAfw==").media;
+        let decoded = format.decode(&media.decode_payload().unwrap());
+        assert_eq!(decoded, vec![vec![0, 32124], vec![-32124, 0]]);
+    }
+
+    #[test]
+    fn media_format_is_decoding_mono_audio() {
+        let TwilioMessage::Start(start) = serde_json::from_str(START).unwrap() else {
+            panic!("expected a start message");
+        };
+        let format = start.start.media_format;
+        assert!(!format.is_stereo());
+
+        assert_eq!(
+            format.decode(&[0xFF, 0x00, 0x80]),
+            vec![vec![0, -32124, 32124]]
+        );
+    }
+
+    const CONNECTED: &str = r#"{"event": "connected", "protocol": "Call", "version": "1.0.0"}"#;
+    const START: &str = r#"{"event": "start", "sequenceNumber": "1", "start": {"accountSid": "AC123", "streamSid": "MZ123", "callSid": "CA123", "tracks": ["inbound"], "mediaFormat": {"encoding": "audio/x-mulaw", "sampleRate": 8000, "channels": 1}, "customParameters": {}}, "streamSid": "MZ123"}"#;
+    const MEDIA: &str = r#"{"event": "media", "sequenceNumber": "2", "media": {"track": "inbound", "chunk": "1", "timestamp": "5", "payload": "no+JhoaJjpzSHxAKBgYJDhtEopGKh4eJjZa7JBIL"}, "streamSid": "MZ123"}"#;
+    const STOP: &str = r#"{"event": "stop", "sequenceNumber": "3", "stop": {"accountSid": "AC123", "callSid": "CA123"}, "streamSid": "MZ123"}"#;
+
+    #[test]
+    fn stream_session_is_walking_full_lifecycle() {
+        let mut session = StreamSession::new();
+        let want = [
+            StreamSessionState::Connected,
+            StreamSessionState::Started,
+            StreamSessionState::Started,
+            StreamSessionState::Stopped,
+        ];
+
+        for (json, want) in [CONNECTED, START, MEDIA, STOP].into_iter().zip(want) {
+            let msg = TwilioMessage::try_from(json).unwrap();
+            assert_eq!(session.handle(&msg).unwrap(), want);
+        }
+
+        assert!(StreamSession::is_stopped(
+            &TwilioMessage::try_from(STOP).unwrap()
+        ));
+        assert_eq!(session.stream_sid.as_deref(), Some("MZ123"));
+        assert_eq!(session.call_sid.as_deref(), Some("CA123"));
+    }
+
+    #[test]
+    fn stream_session_is_erring_when_media_arrives_before_start() {
+        let mut session = StreamSession::new();
+        session
+            .handle(&TwilioMessage::try_from(CONNECTED).unwrap())
+            .unwrap();
+
+        let got = session.handle(&TwilioMessage::try_from(MEDIA).unwrap());
+        assert!(got.is_err());
+        if let Err(e) = got {
+            assert_eq!(
+                e.to_string(),
+                "unexpected media message while stream session is connected"
+            );
+        }
+        assert_eq!(session.state, StreamSessionState::Connected);
+    }
+
+    #[test]
+    fn media_message_sequence_is_parsing_sequence_number() {
+        let json = r#"{"event": "media", "sequenceNumber": "3", "media": {"track": "outbound", "chunk": "1", "timestamp": "5", "payload": "no+JhoaJjpzSHxAKBgYJDhtEopGKh4eJjZa7JBIL"}, "streamSid": "MZ123"}"#;
+        let msg = TwilioMessage::try_from(json).unwrap();
+
+        assert!(matches!(msg, TwilioMessage::Media(_)));
+        assert_eq!(msg.sequence(), Some(3));
+    }
+
+    #[test]
+    fn media_message_sequence_is_none_when_sequence_number_is_missing() {
+        let msg = MediaMessage::new("MZ123", "payload");
+        assert_eq!(msg.sequence(), None);
+        assert_eq!(TwilioMessage::Media(msg).sequence(), None);
+    }
+
+    #[test]
+    fn mark_message_sequence_is_none_when_sequence_number_is_not_numeric() {
+        let json = r#"{"event": "mark", "sequenceNumber": "four", "streamSid": "MZ123", "mark": {"name": "my label"}}"#;
+        let msg = TwilioMessage::try_from(json).unwrap();
+
+        assert!(matches!(msg, TwilioMessage::Mark(_)));
+        assert_eq!(msg.sequence(), None);
+    }
+
+    #[test]
+    fn stop_message_sequence_is_parsing_sequence_number() {
+        let json = r#"{"event": "stop", "sequenceNumber": "5", "stop": {"accountSid": "AC123", "callSid": "CA123"}, "streamSid": "MZ123"}"#;
+        let msg = TwilioMessage::try_from(json).unwrap();
+
+        assert!(matches!(msg, TwilioMessage::Stop(_)));
+        assert_eq!(msg.sequence(), Some(5));
+    }
+}
@@ -0,0 +1,256 @@
+//! Transcription endpoints
+//! See [Transcription resource reference](https://www.twilio.com/docs/voice/api/recording-transcription)
+use super::*;
+use crate::endpoints::applications::ApiVersion;
+use crate::url::query::TwilioQuery;
+use strum::Display;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [Transcription Properties](https://www.twilio.com/docs/voice/api/recording-transcription#transcription-properties)
+pub struct TranscriptionResponse {
+    /// The SID of the Account that created the Transcription resource.
+    pub account_sid: String,
+    /// The API version used to create the transcription.
+    pub api_version: Option<ApiVersion>,
+    /// The date and time in GMT that the resource was created specified in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The date and time in GMT that the resource was last updated specified in RFC 2822 format.
+    pub date_updated: Option<String>,
+    /// The duration of the transcribed audio in seconds.
+    pub duration: Option<String>,
+    /// The charge for the transcript in the currency associated with the account.
+    pub price: Option<String>,
+    /// The currency in which price is measured, in ISO 4127 format (e.g. USD, EUR, JPY).
+    pub price_unit: Option<String>,
+    /// The SID of the Recording from which the transcription was created.
+    pub recording_sid: Option<String>,
+    /// The unique string that that we created to identify the Transcription resource.
+    pub sid: String,
+    /// The status of the transcription.
+    pub status: Option<TranscriptionStatus>,
+    /// The text content of the transcription.
+    pub transcription_text: Option<String>,
+    /// The transcription type. Can only be: fast.
+    pub r#type: Option<String>,
+    /// The URI of the resource, relative to https://api.twilio.com.
+    pub uri: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Display, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum TranscriptionStatus {
+    InProgress,
+    Completed,
+    Failed,
+    /// A status added by Twilio after this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchTranscription {
+    pub account_sid: String,
+    pub transcription_sid: String,
+}
+
+impl FetchTranscription {
+    pub fn new(account_sid: impl Into<String>, transcription_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            transcription_sid: transcription_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for FetchTranscription {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Transcriptions/{Sid}.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = TranscriptionResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.transcription_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ListTranscriptions {
+    pub account_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListTranscriptions {
+    pub fn new(account_sid: impl Into<String>, query: TwilioQuery<Self>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListTranscriptions {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Transcriptions.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListTranscriptionsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("{AccountSid}", &self.account_sid)]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+/// Lists the transcriptions created from one recording.
+#[derive(Clone, Debug)]
+pub struct ListRecordingTranscriptions {
+    pub account_sid: String,
+    pub recording_sid: String,
+    pub query: TwilioQuery<Self>,
+}
+
+impl ListRecordingTranscriptions {
+    pub fn new(
+        account_sid: impl Into<String>,
+        recording_sid: impl Into<String>,
+        query: TwilioQuery<Self>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            recording_sid: recording_sid.into(),
+            query,
+        }
+    }
+}
+
+impl TwilioEndpoint for ListRecordingTranscriptions {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Recordings/{RecordingSid}/Transcriptions.json";
+
+    const METHOD: Method = Method::GET;
+
+    type ResponseBody = ListTranscriptionsResponse;
+
+    fn query_params(&self) -> Option<QueryValues> {
+        Some(self.query.params.clone())
+    }
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{RecordingSid}", &self.recording_sid),
+        ]
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListTranscriptionsResponse {
+    pub transcriptions: Vec<TranscriptionResponse>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+impl_paginated!(
+    ListTranscriptionsResponse,
+    transcriptions,
+    TranscriptionResponse
+);
+
+#[derive(Clone, Debug)]
+pub struct DeleteTranscription {
+    pub account_sid: String,
+    pub transcription_sid: String,
+}
+
+impl DeleteTranscription {
+    pub fn new(account_sid: impl Into<String>, transcription_sid: impl Into<String>) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            transcription_sid: transcription_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteTranscription {
+    const PATH: &'static str = "/2010-04-01/Accounts/{AccountSid}/Transcriptions/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{Sid}", &self.transcription_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::Paginated;
+
+    #[test]
+    fn list_transcriptions_response_is_deserializing() {
+        let json = r#"{"transcriptions": [{"account_sid": "AC123", "api_version": "2010-04-01", "date_created": "Thu, 25 Aug 2011 20:59:45 +0000", "date_updated": "Thu, 25 Aug 2011 20:59:45 +0000", "duration": "10", "price": "0.0", "price_unit": "USD", "recording_sid": "RE123", "sid": "TR123", "status": "completed", "transcription_text": "Hello world", "type": "fast", "uri": "/2010-04-01/Accounts/AC123/Transcriptions/TR123.json"}], "page": 0, "page_size": 50, "first_page_uri": "/first", "end": 0, "start": 0, "uri": "/uri", "next_page_uri": null, "previous_page_uri": null}"#;
+        let resp: ListTranscriptionsResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.items().len(), 1);
+        let transcription = &resp.transcriptions[0];
+        assert_eq!(transcription.recording_sid.as_deref(), Some("RE123"));
+        assert_eq!(
+            transcription.transcription_text.as_deref(),
+            Some("Hello world")
+        );
+        assert_eq!(transcription.r#type.as_deref(), Some("fast"));
+        assert_eq!(transcription.status, Some(TranscriptionStatus::Completed));
+    }
+
+    #[test]
+    fn list_recording_transcriptions_is_scoping_path_to_recording() {
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+        let url =
+            ListRecordingTranscriptions::new("AC123", "RE123", TwilioQuery::new()).url(&base_url);
+
+        assert_eq!(
+            url.path(),
+            "/2010-04-01/Accounts/AC123/Recordings/RE123/Transcriptions.json"
+        );
+    }
+
+    #[test]
+    fn transcription_status_is_deserializing_in_progress() {
+        let status: TranscriptionStatus = serde_json::from_str(r#""in-progress""#).unwrap();
+
+        assert_eq!(status, TranscriptionStatus::InProgress);
+        assert_eq!(status.to_string(), "in-progress");
+
+        let status: TranscriptionStatus = serde_json::from_str(r#""queued""#).unwrap();
+        assert_eq!(status, TranscriptionStatus::Unknown);
+    }
+}
@@ -0,0 +1,234 @@
+//! UserDefinedMessage and UserDefinedMessageSubscription endpoints
+//! See [UserDefinedMessage resource reference](https://www.twilio.com/docs/voice/api/userdefinedmessage-resource)
+use super::*;
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [UserDefinedMessage Properties](https://www.twilio.com/docs/voice/api/userdefinedmessage-resource#userdefinedmessage-properties)
+pub struct UserDefinedMessageResponse {
+    /// The SID of the Account that created User Defined Message.
+    pub account_sid: String,
+    /// The SID of the Call the User Defined Message is associated with.
+    pub call_sid: String,
+    /// The SID that uniquely identifies this User Defined Message.
+    pub sid: String,
+    /// The date that this User Defined Message was created, given in RFC 2822 format.
+    pub date_created: Option<String>,
+}
+
+/// Sends a message to the application on the other end of an active call, e.g. a
+/// bidirectional media stream.
+#[derive(Debug)]
+pub struct CreateUserDefinedMessage<'a> {
+    pub account_sid: String,
+    pub call_sid: String,
+    pub body: RequestBody<CreateUserDefinedMessageBody<'a>>,
+}
+
+impl<'a> CreateUserDefinedMessage<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        call_sid: impl Into<String>,
+        body: CreateUserDefinedMessageBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            call_sid: call_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateUserDefinedMessageBody<'a> {
+    /// A JSON object, e.g. `{"key": "value"}`.
+    pub content: &'a str,
+    /// Makes retries safe: Twilio drops a message whose key it has already seen.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<&'a str>,
+}
+
+impl<'a> CreateUserDefinedMessageBody<'a> {
+    pub fn new(content: &'a str) -> Self {
+        Self {
+            content,
+            idempotency_key: None,
+        }
+    }
+}
+
+impl TwilioEndpoint for CreateUserDefinedMessage<'_> {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Calls/{CallSid}/UserDefinedMessages.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = UserDefinedMessageResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CallSid}", &self.call_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+/// See [UserDefinedMessageSubscription Properties](https://www.twilio.com/docs/voice/api/userdefinedmessagesubscription-resource#userdefinedmessagesubscription-properties)
+pub struct UserDefinedMessageSubscriptionResponse {
+    /// The SID of the Account that subscribed to the User Defined Messages.
+    pub account_sid: String,
+    /// The SID of the Call the User Defined Message Subscription is associated with.
+    pub call_sid: String,
+    /// The SID that uniquely identifies this User Defined Message Subscription.
+    pub sid: String,
+    /// The date that this User Defined Message Subscription was created, given in RFC 2822 format.
+    pub date_created: Option<String>,
+    /// The URI of the User Defined Message Subscription Resource, relative to `https://api.twilio.com`.
+    pub uri: String,
+}
+
+/// Subscribes `callback` to the messages the application on the other end of a call sends.
+#[derive(Debug)]
+pub struct CreateUserDefinedMessageSubscription<'a> {
+    pub account_sid: String,
+    pub call_sid: String,
+    pub body: RequestBody<CreateUserDefinedMessageSubscriptionBody<'a>>,
+}
+
+impl<'a> CreateUserDefinedMessageSubscription<'a> {
+    pub fn new(
+        account_sid: impl Into<String>,
+        call_sid: impl Into<String>,
+        body: CreateUserDefinedMessageSubscriptionBody<'a>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            call_sid: call_sid.into(),
+            body: RequestBody::Form(body),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateUserDefinedMessageSubscriptionBody<'a> {
+    /// The URL Twilio sends each User Defined Message to.
+    pub callback: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<&'a str>,
+    /// `GET` or `POST`; Twilio defaults to `POST`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<&'a str>,
+}
+
+impl<'a> CreateUserDefinedMessageSubscriptionBody<'a> {
+    pub fn new(callback: &'a str) -> Self {
+        Self {
+            callback,
+            ..Default::default()
+        }
+    }
+}
+
+impl TwilioEndpoint for CreateUserDefinedMessageSubscription<'_> {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Calls/{CallSid}/UserDefinedMessageSubscriptions.json";
+
+    const METHOD: Method = Method::POST;
+
+    type ResponseBody = UserDefinedMessageSubscriptionResponse;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CallSid}", &self.call_sid),
+        ]
+    }
+
+    fn configure_request_body(self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        self.body.configure(builder)
+    }
+
+    async fn response_body(resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteUserDefinedMessageSubscription {
+    pub account_sid: String,
+    pub call_sid: String,
+    pub subscription_sid: String,
+}
+
+impl DeleteUserDefinedMessageSubscription {
+    pub fn new(
+        account_sid: impl Into<String>,
+        call_sid: impl Into<String>,
+        subscription_sid: impl Into<String>,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.into(),
+            call_sid: call_sid.into(),
+            subscription_sid: subscription_sid.into(),
+        }
+    }
+}
+
+impl TwilioEndpoint for DeleteUserDefinedMessageSubscription {
+    const PATH: &'static str =
+        "/2010-04-01/Accounts/{AccountSid}/Calls/{CallSid}/UserDefinedMessageSubscriptions/{Sid}.json";
+
+    const METHOD: Method = Method::DELETE;
+
+    type ResponseBody = ();
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("{AccountSid}", &self.account_sid),
+            ("{CallSid}", &self.call_sid),
+            ("{Sid}", &self.subscription_sid),
+        ]
+    }
+
+    async fn response_body(_resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_user_defined_message_body_is_sending_json_content() {
+        let body = CreateUserDefinedMessageBody {
+            idempotency_key: Some("retry-1"),
+            ..CreateUserDefinedMessageBody::new(r#"{"example_key": "Hello"}"#)
+        };
+        let got = serde_urlencoded::to_string(&body).unwrap();
+
+        assert_eq!(
+            got,
+            "Content=%7B%22example_key%22%3A+%22Hello%22%7D&IdempotencyKey=retry-1"
+        );
+    }
+
+    #[test]
+    fn user_defined_message_subscription_response_is_deserializing() {
+        let json = r#"{"account_sid": "AC123", "call_sid": "CA123", "sid": "ZY123", "date_created": "Wed, 18 Dec 2019 20:02:01 +0000", "uri": "/2010-04-01/Accounts/AC123/Calls/CA123/UserDefinedMessageSubscriptions/ZY123.json"}"#;
+        let resp: UserDefinedMessageSubscriptionResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.sid, "ZY123");
+        assert_eq!(resp.call_sid, "CA123");
+    }
+}
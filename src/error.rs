@@ -1,10 +1,13 @@
+use crate::endpoints::voice::call::CallStatus;
+use crate::endpoints::voice::stream::StreamSessionState;
 use crate::validation::SignatureValidationError;
 use serde::Deserialize;
 use thiserror::Error;
+use url::Url;
 
 #[derive(Error, Debug)]
 pub enum TwilioError {
-    #[error("API error ({status}): {error:?}")]
+    #[error("API error ({status}): {error}")]
     Api {
         status: reqwest::StatusCode,
         error: TwilioApiError,
@@ -27,14 +30,42 @@ pub enum TwilioError {
     InvalidCallbackUrl(String),
     #[error("XML writing error: {0}")]
     Xml(#[from] xml::writer::Error),
+    #[error("base64 error: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("TwiML parsing error: {0}")]
+    TwimlParse(String),
     #[error("UTF-8 encoding error: {0}")]
     Utf8(#[from] std::string::FromUtf8Error),
     #[error("signature validation error: {0}")]
     Validation(#[from] SignatureValidationError),
     #[error("unsupported noun")]
     UnsupportedNoun,
+    #[error("unsupported verb")]
+    UnsupportedVerb,
     #[error("validation error: {0}")]
     Validator(#[from] validator::ValidationErrors),
+    #[error("participant label already in use: {0}")]
+    DuplicateLabel(String),
+    #[error("call is already {0}")]
+    CallNotLive(CallStatus),
+    #[error("participant {call_sid} did not connect within {timeout:?}")]
+    ParticipantTimeout {
+        call_sid: String,
+        timeout: std::time::Duration,
+    },
+    #[error("the account SID and auth token were rejected")]
+    InvalidCredentials,
+    #[error("circuit breaker is open")]
+    CircuitOpen,
+    #[error("response body is over the {limit} byte limit")]
+    ResponseTooLarge { limit: usize },
+    #[error("inline TwiML is {0} bytes, over the {max} byte limit", max = crate::endpoints::voice::call::MAX_TWIML_BYTES)]
+    TwimlTooLarge(usize),
+    #[error("unexpected {event} message while stream session is {state}")]
+    UnexpectedStreamMessage {
+        state: StreamSessionState,
+        event: String,
+    },
 }
 
 #[allow(dead_code)]
@@ -45,3 +76,49 @@ pub struct TwilioApiError {
     more_info: String,
     status: u16,
 }
+
+impl TwilioApiError {
+    /// The link to Twilio's documentation for this error code.
+    pub fn more_info_url(&self) -> Option<Url> {
+        Url::parse(&self.more_info).ok()
+    }
+}
+
+impl std::fmt::Display for TwilioApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (code {})", self.message, self.code)?;
+        if let Some(url) = self.more_info_url() {
+            write!(f, ", see {url}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOT_FOUND: &str = r#"{"code": 20404, "message": "The requested resource was not found", "more_info": "https://www.twilio.com/docs/errors/20404", "status": 404}"#;
+
+    #[test]
+    fn twilio_api_error_is_parsing_more_info_url() {
+        let error: TwilioApiError = serde_json::from_str(NOT_FOUND).unwrap();
+
+        let url = error.more_info_url().unwrap();
+        assert_eq!(url.host_str(), Some("www.twilio.com"));
+        assert_eq!(url.path(), "/docs/errors/20404");
+    }
+
+    #[test]
+    fn twilio_error_api_is_displaying_more_info_url() {
+        let error = TwilioError::Api {
+            status: reqwest::StatusCode::NOT_FOUND,
+            error: serde_json::from_str(NOT_FOUND).unwrap(),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "API error (404 Not Found): The requested resource was not found (code 20404), see https://www.twilio.com/docs/errors/20404"
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::endpoints::{Paginated, Pagination};
+use crate::Result;
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// The items of a list page, parsed one by one as the response body arrives instead of after
+/// it has been buffered whole, see [`TwilioClient::hit_streaming`](crate::TwilioClient::hit_streaming).
+///
+/// Only the item being parsed and the page's other fields are held in memory, so a large
+/// page of calls or messages costs about as much as one of its items.
+pub struct ItemStream<P: Paginated> {
+    resp: reqwest::Response,
+    scanner: ItemScanner,
+    ready: VecDeque<Vec<u8>>,
+    done: bool,
+    page: PhantomData<P>,
+}
+
+impl<P> ItemStream<P>
+where
+    P: Paginated,
+    P::Item: DeserializeOwned,
+{
+    pub(crate) fn new(resp: reqwest::Response) -> Self {
+        Self {
+            resp,
+            scanner: ItemScanner::new(P::ITEMS_FIELD),
+            ready: VecDeque::new(),
+            done: false,
+            page: PhantomData,
+        }
+    }
+
+    /// The next item, `None` once the page has been read.
+    pub async fn next(&mut self) -> Option<Result<P::Item>> {
+        loop {
+            if let Some(item) = self.ready.pop_front() {
+                return Some(serde_json::from_slice(&item).map_err(Into::into));
+            }
+            if self.done {
+                return None;
+            }
+            match self.resp.chunk().await {
+                Ok(Some(chunk)) => self.scanner.feed(&chunk, &mut self.ready),
+                Ok(None) => self.done = true,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e.into()));
+                }
+            }
+        }
+    }
+
+    /// The page's pagination, once [`ItemStream::next`] has returned `None`.
+    pub fn pagination(&self) -> Option<Pagination> {
+        if !self.done {
+            return None;
+        }
+        serde_json::from_slice(&self.scanner.rest).ok()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn scanner_rest_len(&self) -> usize {
+        self.scanner.rest.len()
+    }
+}
+
+/// Splits a page's JSON into the raw bytes of each element of its `field` array, keeping the
+/// rest of the document, with the array emptied, for the pagination fields.
+struct ItemScanner {
+    field: &'static str,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    /// The last string read directly inside the page object, i.e. the key of an array that
+    /// opens next.
+    key: Vec<u8>,
+    in_items: bool,
+    item: Option<Vec<u8>>,
+    rest: Vec<u8>,
+}
+
+impl ItemScanner {
+    fn new(field: &'static str) -> Self {
+        Self {
+            field,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            key: Vec::new(),
+            in_items: false,
+            item: None,
+            rest: Vec::new(),
+        }
+    }
+
+    fn feed(&mut self, chunk: &[u8], items: &mut VecDeque<Vec<u8>>) {
+        for &b in chunk {
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if b == b'\\' {
+                    self.escaped = true;
+                } else if b == b'"' {
+                    self.in_string = false;
+                } else if self.depth == 1 {
+                    self.key.push(b);
+                }
+            } else {
+                match b {
+                    b'"' => {
+                        self.in_string = true;
+                        if self.depth == 1 {
+                            self.key.clear();
+                        }
+                    }
+                    b'[' if self.depth == 1 && self.key == self.field.as_bytes() => {
+                        self.in_items = true;
+                        self.depth += 1;
+                        self.rest.push(b);
+                        continue;
+                    }
+                    b'{' | b'[' => {
+                        if self.in_items && self.depth == 2 {
+                            self.item = Some(Vec::new());
+                        }
+                        self.depth += 1;
+                    }
+                    b'}' | b']' => {
+                        self.depth = self.depth.saturating_sub(1);
+                        if self.in_items && self.depth == 1 {
+                            self.in_items = false;
+                            self.rest.push(b);
+                            continue;
+                        }
+                        if self.in_items && self.depth == 2 {
+                            if let Some(mut item) = self.item.take() {
+                                item.push(b);
+                                items.push_back(item);
+                            }
+                            continue;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            match &mut self.item {
+                Some(item) => item.push(b),
+                None if !self.in_items => self.rest.push(b),
+                None => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_scanner_is_splitting_items_across_chunks() {
+        let page = r#"{"page": 0, "calls": [{"sid": "CA1", "to": "a \"[x]\" }"}, {"sid": "CA2", "nested": {"list": [1, 2]}}], "uri": "/uri"}"#;
+        let mut scanner = ItemScanner::new("calls");
+        let mut items = VecDeque::new();
+
+        for byte in page.as_bytes().chunks(1) {
+            scanner.feed(byte, &mut items);
+        }
+
+        let items: Vec<_> = items
+            .iter()
+            .map(|item| std::str::from_utf8(item).unwrap())
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                r#"{"sid": "CA1", "to": "a \"[x]\" }"}"#,
+                r#"{"sid": "CA2", "nested": {"list": [1, 2]}}"#,
+            ]
+        );
+        assert_eq!(
+            std::str::from_utf8(&scanner.rest).unwrap(),
+            r#"{"page": 0, "calls": [], "uri": "/uri"}"#
+        );
+    }
+}
@@ -1,14 +1,26 @@
+mod callback_urls;
+mod circuit_breaker;
 mod client;
 mod client_ext;
+mod edge;
 pub mod endpoints;
 pub mod error;
+mod item_stream;
+#[cfg(test)]
+mod mock_server;
 pub mod request_parameters;
+mod retry;
 pub mod twiml;
 pub mod url;
 pub mod validation;
 
+pub use callback_urls::CallbackUrls;
+pub use circuit_breaker::CircuitBreaker;
 pub use client::TwilioClient;
 pub use client_ext::TwilioClientExt;
+pub use edge::Edge;
+pub use item_stream::ItemStream;
+pub use retry::RetryPolicy;
 pub use url::query::TwilioQuery;
 
 pub type Result<T> = std::result::Result<T, error::TwilioError>;
@@ -0,0 +1,156 @@
+//! A minimal HTTP/1.1 server for exercising [`TwilioClient::hit`](crate::TwilioClient::hit)
+//! in tests. Each connection gets one response and is then closed.
+#![allow(dead_code)]
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use url::Url;
+
+#[derive(Clone, Debug)]
+pub(crate) struct MockRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl MockResponse {
+    pub fn json(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Type".into(), "application/json".into())],
+            body: body.into().into_bytes(),
+        }
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+type Responder = dyn Fn(&MockRequest) -> MockResponse + Send + Sync;
+
+pub(crate) struct MockServer {
+    url: Url,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockServer {
+    /// Serves every request with `responder` until the test process exits.
+    pub fn start(responder: impl Fn(&MockRequest) -> MockResponse + Send + Sync + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let responder: Arc<Responder> = Arc::new(responder);
+
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let recorded = recorded.clone();
+                let responder = responder.clone();
+                thread::spawn(move || serve(stream, &recorded, responder.as_ref()));
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// Serves each request with the next response in order, repeating the last one.
+    pub fn sequence(responses: Vec<MockResponse>) -> Self {
+        let next = Mutex::new(0);
+        Self::start(move |_| {
+            let mut next = next.lock().unwrap();
+            let resp = responses[(*next).min(responses.len() - 1)].clone();
+            *next += 1;
+            resp
+        })
+    }
+
+    pub fn url(&self) -> Url {
+        self.url.clone()
+    }
+
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn serve(stream: TcpStream, recorded: &Mutex<Vec<MockRequest>>, responder: &Responder) {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    if reader.read_line(&mut line).is_err() {
+        return;
+    }
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+            break;
+        }
+        if let Some((k, v)) = line.trim_end().split_once(':') {
+            headers.push((k.trim().to_string(), v.trim().to_string()));
+        }
+    }
+
+    let request = MockRequest {
+        method,
+        path,
+        headers,
+        body: String::new(),
+    };
+    let len = request
+        .header("Content-Length")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; len];
+    if reader.read_exact(&mut body).is_err() {
+        return;
+    }
+    let request = MockRequest {
+        body: String::from_utf8_lossy(&body).into_owned(),
+        ..request
+    };
+
+    let response = responder(&request);
+    recorded.lock().unwrap().push(request);
+
+    let mut out = format!("HTTP/1.1 {} Mock\r\nConnection: close\r\n", response.status);
+    if !response
+        .headers
+        .iter()
+        .any(|(k, _)| k.eq_ignore_ascii_case("Content-Length"))
+    {
+        out.push_str(&format!("Content-Length: {}\r\n", response.body.len()));
+    }
+    for (k, v) in &response.headers {
+        out.push_str(&format!("{k}: {v}\r\n"));
+    }
+    out.push_str("\r\n");
+
+    let mut stream = reader.into_inner();
+    let _ = stream.write_all(out.as_bytes());
+    let _ = stream.write_all(&response.body);
+}
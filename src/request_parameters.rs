@@ -1,5 +1,6 @@
 use crate::endpoints::applications::ApiVersion;
-use crate::endpoints::voice::call::CallStatus;
+use crate::endpoints::voice::call::{CallDirection, CallStatus};
+use crate::endpoints::voice::conference::{FetchConference, FetchParticipant, ListParticipants};
 use crate::endpoints::Deserialize;
 use std::collections::HashMap;
 
@@ -13,7 +14,7 @@ pub struct TwilioRequestParams {
     pub to: String,
     pub call_status: CallStatus,
     pub api_version: ApiVersion,
-    pub direction: String,
+    pub direction: CallDirection,
     pub forwarded_from: Option<String>,
     pub caller_name: Option<String>,
     pub parent_call_sid: Option<String>,
@@ -26,8 +27,12 @@ pub struct TwilioRequestParams {
     pub to_state: Option<String>,
     pub to_zip: Option<String>,
     pub to_country: Option<String>,
+    /// Parameters not covered above; a repeated key keeps only its last value.
     #[serde(flatten)]
     pub extra: HashMap<String, String>,
+    /// The same parameters as `extra`, keeping every value of a repeated key in order.
+    #[serde(flatten, deserialize_with = "deserialize_multi_map")]
+    pub extra_multi: HashMap<String, Vec<String>>,
 }
 
 impl TwilioRequestParams {
@@ -37,6 +42,99 @@ impl TwilioRequestParams {
     pub fn get_extra(&self, key: &str) -> Option<&String> {
         self.extra.get(key)
     }
+
+    /// Every value sent for `key`, empty if it wasn't sent.
+    pub fn get_extra_all(&self, key: &str) -> &[String] {
+        self.extra_multi
+            .get(key)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// A loggable view of the request with the caller's and callee's numbers and the caller's
+    /// name masked, e.g. `+1******1212`.
+    pub fn redacted(&self) -> RedactedParams<'_> {
+        RedactedParams(self)
+    }
+}
+
+/// Displays the identifying parts of [`TwilioRequestParams`] with personal data masked,
+/// see [`TwilioRequestParams::redacted`].
+///
+/// Location and extra parameters are left out rather than masked, since they may hold
+/// anything the caller entered.
+pub struct RedactedParams<'a>(&'a TwilioRequestParams);
+
+impl std::fmt::Display for RedactedParams<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params = self.0;
+        write!(
+            f,
+            "CallSid={} AccountSid={} From={} To={} CallStatus={} Direction={}",
+            params.call_sid,
+            params.account_sid,
+            mask_number(&params.from),
+            mask_number(&params.to),
+            params.call_status,
+            params.direction,
+        )?;
+        if let Some(forwarded_from) = &params.forwarded_from {
+            write!(f, " ForwardedFrom={}", mask_number(forwarded_from))?;
+        }
+        if let Some(caller_name) = &params.caller_name {
+            write!(f, " CallerName={}", mask_name(caller_name))?;
+        }
+        Ok(())
+    }
+}
+
+/// Keeps the first two and last four characters of numbers long enough to still be masked.
+fn mask_number(number: &str) -> String {
+    let len = number.chars().count();
+    if len <= 6 {
+        return "*".repeat(len);
+    }
+    number
+        .chars()
+        .enumerate()
+        .map(|(i, c)| if i < 2 || i >= len - 4 { c } else { '*' })
+        .collect()
+}
+
+fn mask_name(name: &str) -> String {
+    name.chars()
+        .enumerate()
+        .map(|(i, c)| if i == 0 { c } else { '*' })
+        .collect()
+}
+
+/// Collects a map whose keys may repeat, as form bodies' can, instead of keeping the last value.
+fn deserialize_multi_map<'de, D>(deserializer: D) -> Result<HashMap<String, Vec<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct MultiMapVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for MultiMapVisitor {
+        type Value = HashMap<String, Vec<String>>;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a map of strings")
+        }
+
+        fn visit_map<A: serde::de::MapAccess<'de>>(
+            self,
+            mut map: A,
+        ) -> Result<Self::Value, A::Error> {
+            let mut values: Self::Value = HashMap::new();
+            while let Some((key, value)) = map.next_entry::<String, String>()? {
+                values.entry(key).or_default().push(value);
+            }
+            Ok(values)
+        }
+    }
+
+    deserializer.deserialize_map(MultiMapVisitor)
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -74,6 +172,23 @@ impl ConferenceRequestParams {
             .map(|e| e == &ConferenceEvent::ConferenceEnd)
             .unwrap_or_default()
     }
+
+    /// Fetches the conference this event is about.
+    pub fn conference(&self) -> FetchConference {
+        FetchConference::new(&self.account_sid, &self.conference_sid)
+    }
+
+    /// Lists the conference's participants.
+    pub fn participants(&self) -> ListParticipants {
+        ListParticipants::new(&self.account_sid, &self.conference_sid)
+    }
+
+    /// Fetches the participant the event is about, if it's a participant event.
+    pub fn participant(&self) -> Option<FetchParticipant> {
+        self.call_sid.as_ref().map(|call_sid| {
+            FetchParticipant::new(&self.account_sid, &self.conference_sid, call_sid)
+        })
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq)]
@@ -113,3 +228,140 @@ pub enum AnsweredBy {
     MachineEndSilence,
     MachineEndOther,
 }
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+/// See [Dial Action](https://www.twilio.com/docs/voice/twiml/dial#action)
+pub struct DialActionParams {
+    pub call_sid: String,
+    pub account_sid: String,
+    pub dial_call_status: DialCallStatus,
+    pub dial_call_sid: Option<String>,
+    pub dial_call_duration: Option<u32>,
+    pub dial_bridged: Option<bool>,
+    pub recording_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+/// See [DialCallStatus Values](https://www.twilio.com/docs/voice/twiml/dial#dialcallstatus-values)
+#[serde(rename_all = "kebab-case")]
+pub enum DialCallStatus {
+    Completed,
+    Answered,
+    Busy,
+    NoAnswer,
+    Failed,
+    Canceled,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+/// See [Enqueue Action](https://www.twilio.com/docs/voice/twiml/enqueue#attributes-action)
+pub struct EnqueueActionParams {
+    pub call_sid: String,
+    pub account_sid: String,
+    pub queue_result: QueueResult,
+    pub queue_sid: Option<String>,
+    /// Seconds the caller spent in the queue.
+    pub queue_time: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+/// See [QueueResult Values](https://www.twilio.com/docs/voice/twiml/enqueue#attributes-action-parameters)
+#[serde(rename_all = "kebab-case")]
+pub enum QueueResult {
+    Bridged,
+    QueueFull,
+    Redirected,
+    Hangup,
+    Error,
+    Leave,
+    SystemError,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::TwilioEndpoint;
+    use url::Url;
+
+    #[test]
+    fn conference_request_params_is_building_related_endpoints() {
+        let body = "ConferenceSid=CF123&FriendlyName=standup&AccountSid=AC123&SequenceNumber=1&Timestamp=Tue%2C%2001%20Oct%202024%2012%3A00%3A00%20%2B0000&StatusCallbackEvent=participant-join&CallSid=CA123";
+        let params: ConferenceRequestParams = serde_urlencoded::from_str(body).unwrap();
+        let base_url = Url::parse("https://api.twilio.com").unwrap();
+
+        assert_eq!(
+            params.conference().url(&base_url).path(),
+            "/2010-04-01/Accounts/AC123/Conferences/CF123.json"
+        );
+        assert_eq!(
+            params.participants().url(&base_url).path(),
+            "/2010-04-01/Accounts/AC123/Conferences/CF123/Participants.json"
+        );
+        assert_eq!(
+            params.participant().unwrap().url(&base_url).path(),
+            "/2010-04-01/Accounts/AC123/Conferences/CF123/Participants/CA123.json"
+        );
+    }
+
+    #[test]
+    fn twilio_request_params_redacted_is_masking_personal_data() {
+        let body = "CallSid=CA123&AccountSid=AC123&From=%2B16175551212&To=%2B15558675310&CallStatus=ringing&ApiVersion=2010-04-01&Direction=inbound&CallerName=Jane%20Doe&FromCity=BOSTON&Digits=1234";
+        let params: TwilioRequestParams = serde_urlencoded::from_str(body).unwrap();
+
+        assert_eq!(
+            params.redacted().to_string(),
+            "CallSid=CA123 AccountSid=AC123 From=+1******1212 To=+1******5310 CallStatus=ringing Direction=inbound CallerName=J*******"
+        );
+        assert_eq!(mask_number("1234"), "****");
+    }
+
+    #[test]
+    fn twilio_request_params_is_keeping_repeated_extra_params() {
+        let body = "CallSid=CA123&AccountSid=AC123&From=%2B15017122661&To=%2B15558675310&CallStatus=ringing&ApiVersion=2010-04-01&Direction=inbound&RecordingStatusCallbackEvent=in-progress&RecordingStatusCallbackEvent=completed&Custom=1";
+        let params: TwilioRequestParams = serde_urlencoded::from_str(body).unwrap();
+
+        assert_eq!(
+            params.get_extra_all("RecordingStatusCallbackEvent"),
+            ["in-progress", "completed"]
+        );
+        assert_eq!(params.get_extra_all("Custom"), ["1"]);
+        assert!(params.get_extra_all("Missing").is_empty());
+        assert!(!params.extra_multi.contains_key("CallSid"));
+        assert_eq!(
+            params
+                .get_extra("RecordingStatusCallbackEvent")
+                .map(String::as_str),
+            Some("completed")
+        );
+    }
+
+    #[test]
+    fn dial_action_params_is_parsing_form_body() {
+        let body = "CallSid=CA123&AccountSid=AC123&DialCallStatus=no-answer&DialCallSid=CA456&DialCallDuration=0";
+        let params: DialActionParams = serde_urlencoded::from_str(body).unwrap();
+
+        assert_eq!(params.call_sid, "CA123");
+        assert_eq!(params.dial_call_status, DialCallStatus::NoAnswer);
+        assert_eq!(params.dial_call_sid.as_deref(), Some("CA456"));
+        assert_eq!(params.dial_call_duration, Some(0));
+        assert!(params.recording_url.is_none());
+    }
+
+    #[test]
+    fn enqueue_action_params_is_parsing_form_body() {
+        let body =
+            "CallSid=CA123&AccountSid=AC123&QueueResult=queue-full&QueueSid=QU123&QueueTime=42";
+        let params: EnqueueActionParams = serde_urlencoded::from_str(body).unwrap();
+
+        assert_eq!(params.queue_result, QueueResult::QueueFull);
+        assert_eq!(params.queue_sid.as_deref(), Some("QU123"));
+        assert_eq!(params.queue_time, Some(42));
+
+        let body = "CallSid=CA123&AccountSid=AC123&QueueResult=system-error";
+        let params: EnqueueActionParams = serde_urlencoded::from_str(body).unwrap();
+        assert_eq!(params.queue_result, QueueResult::SystemError);
+        assert!(params.queue_time.is_none());
+    }
+}
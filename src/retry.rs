@@ -0,0 +1,205 @@
+use http::{HeaderMap, Method, StatusCode};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Retries 429 and 5xx responses with exponential backoff, honouring `Retry-After`.
+///
+/// A `POST` is only retried on 429 and 503, which Twilio sends before acting on the request.
+/// Any other 5xx can come after the call was placed or the message sent, so retrying it could
+/// do so twice.
+///
+/// When responses carry `X-RateLimit-Limit`, `X-RateLimit-Remaining` and `X-RateLimit-Reset`
+/// (seconds until the window resets), requests are also paced before they're sent: once under
+/// a tenth of the limit is left the remaining requests are spread over the rest of the window,
+/// and with none left the next request waits for the reset. Without these headers only the
+/// reactive backoff applies. Clones share the rate-limit state.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    rate_limit: Arc<Mutex<Option<RateLimit>>>,
+}
+
+#[derive(Debug)]
+struct RateLimit {
+    limit: u32,
+    remaining: u32,
+    resets_at: Instant,
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32, base_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+            max_delay: Duration::from_secs(30),
+            rate_limit: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Caps the backoff between attempts, 30 seconds by default.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    pub(crate) fn should_retry(&self, method: &Method, status: StatusCode, attempt: u32) -> bool {
+        let retryable = match *method {
+            Method::POST => {
+                status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::SERVICE_UNAVAILABLE
+            }
+            _ => status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error(),
+        };
+        attempt < self.max_retries && retryable
+    }
+
+    /// How long to wait before retry number `attempt` (starting at 0) of a failed request.
+    pub(crate) fn backoff(&self, attempt: u32, headers: &HeaderMap) -> Duration {
+        if let Some(seconds) = header_u64(headers, "Retry-After") {
+            return Duration::from_secs(seconds).min(self.max_delay);
+        }
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay)
+    }
+
+    /// Records the rate-limit headers of a response, if it has them.
+    pub(crate) fn observe(&self, headers: &HeaderMap) {
+        self.observe_at(headers, Instant::now())
+    }
+
+    /// Takes a slot for the next request, returning how long to wait before sending it.
+    pub(crate) fn pacing_delay(&self) -> Duration {
+        self.pacing_delay_at(Instant::now())
+    }
+
+    fn observe_at(&self, headers: &HeaderMap, now: Instant) {
+        let (Some(limit), Some(remaining), Some(reset)) = (
+            header_u64(headers, "X-RateLimit-Limit"),
+            header_u64(headers, "X-RateLimit-Remaining"),
+            header_u64(headers, "X-RateLimit-Reset"),
+        ) else {
+            return;
+        };
+        *self.state() = Some(RateLimit {
+            limit: limit.try_into().unwrap_or(u32::MAX),
+            remaining: remaining.try_into().unwrap_or(u32::MAX),
+            resets_at: now + Duration::from_secs(reset),
+        });
+    }
+
+    fn pacing_delay_at(&self, now: Instant) -> Duration {
+        let mut state = self.state();
+        let Some(rate_limit) = state.as_mut() else {
+            return Duration::ZERO;
+        };
+        if now >= rate_limit.resets_at {
+            *state = None;
+            return Duration::ZERO;
+        }
+
+        let window_left = rate_limit.resets_at - now;
+        let delay = match rate_limit.remaining {
+            0 => window_left,
+            remaining if remaining < rate_limit.limit / 10 => window_left / remaining,
+            _ => Duration::ZERO,
+        };
+        rate_limit.remaining = rate_limit.remaining.saturating_sub(1);
+        delay
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, Option<RateLimit>> {
+        self.rate_limit.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limit_headers(limit: u32, remaining: u32, reset: u64) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", limit.into());
+        headers.insert("X-RateLimit-Remaining", remaining.into());
+        headers.insert("X-RateLimit-Reset", reset.into());
+        headers
+    }
+
+    #[test]
+    fn retry_policy_is_pacing_when_rate_limit_is_nearly_used() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(100));
+        let now = Instant::now();
+
+        policy.observe_at(&rate_limit_headers(100, 50, 10), now);
+        assert_eq!(policy.pacing_delay_at(now), Duration::ZERO);
+
+        policy.observe_at(&rate_limit_headers(100, 5, 10), now);
+        assert_eq!(policy.pacing_delay_at(now), Duration::from_secs(2));
+        assert_eq!(policy.pacing_delay_at(now), Duration::from_millis(2500));
+
+        policy.observe_at(&rate_limit_headers(100, 0, 10), now);
+        assert_eq!(policy.pacing_delay_at(now), Duration::from_secs(10));
+
+        // the window has reset, so requests flow freely until new headers arrive
+        let later = now + Duration::from_secs(11);
+        assert_eq!(policy.pacing_delay_at(later), Duration::ZERO);
+        assert_eq!(policy.pacing_delay_at(later), Duration::ZERO);
+    }
+
+    #[test]
+    fn retry_policy_is_not_pacing_without_rate_limit_headers() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(100));
+        let now = Instant::now();
+
+        let mut headers = rate_limit_headers(100, 0, 10);
+        headers.remove("X-RateLimit-Reset");
+        policy.observe_at(&headers, now);
+
+        assert_eq!(policy.pacing_delay_at(now), Duration::ZERO);
+    }
+
+    #[test]
+    fn retry_policy_is_backing_off_exponentially_or_by_retry_after() {
+        let policy =
+            RetryPolicy::new(5, Duration::from_millis(100)).with_max_delay(Duration::from_secs(1));
+        let headers = HeaderMap::new();
+
+        assert_eq!(policy.backoff(0, &headers), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2, &headers), Duration::from_millis(400));
+        assert_eq!(policy.backoff(10, &headers), Duration::from_secs(1));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("Retry-After", 1.into());
+        assert_eq!(policy.backoff(0, &headers), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn retry_policy_is_retrying_only_throttled_and_server_errors() {
+        let policy = RetryPolicy::new(2, Duration::from_millis(100));
+
+        assert!(policy.should_retry(&Method::GET, StatusCode::TOO_MANY_REQUESTS, 0));
+        assert!(policy.should_retry(&Method::GET, StatusCode::SERVICE_UNAVAILABLE, 1));
+        assert!(policy.should_retry(&Method::DELETE, StatusCode::BAD_GATEWAY, 0));
+        assert!(!policy.should_retry(&Method::GET, StatusCode::SERVICE_UNAVAILABLE, 2));
+        assert!(!policy.should_retry(&Method::GET, StatusCode::BAD_REQUEST, 0));
+    }
+
+    #[test]
+    fn retry_policy_is_not_retrying_post_after_it_may_have_been_acted_on() {
+        let policy = RetryPolicy::new(2, Duration::from_millis(100));
+
+        assert!(policy.should_retry(&Method::POST, StatusCode::TOO_MANY_REQUESTS, 0));
+        assert!(policy.should_retry(&Method::POST, StatusCode::SERVICE_UNAVAILABLE, 0));
+        assert!(!policy.should_retry(&Method::POST, StatusCode::INTERNAL_SERVER_ERROR, 0));
+        assert!(!policy.should_retry(&Method::POST, StatusCode::BAD_GATEWAY, 0));
+    }
+}
@@ -1,3 +1,4 @@
+mod parse;
 pub mod voice;
 use crate::error::TwilioError;
 use xml::writer::EventWriter;
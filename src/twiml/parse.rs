@@ -0,0 +1,251 @@
+//! Reads TwiML back into a [`VoiceResponse`], for the verbs that don't nest other verbs
+//! or nouns.
+use super::voice::{Enqueue, Message, Play, Reject, RejectReason, Say, Verb, VoiceResponse};
+use crate::error::TwilioError;
+use std::str::FromStr;
+use xml::reader::{EventReader, XmlEvent};
+
+impl FromStr for VoiceResponse {
+    type Err = TwilioError;
+
+    /// Parses `<Say>`, `<Play>`, `<Reject>`, `<Leave>`, `<Message>` and `<Enqueue>` verbs,
+    /// erring with [`TwilioError::TwimlParse`] on malformed XML, verbs it can't read, and
+    /// attributes a verb doesn't take.
+    fn from_str(twiml: &str) -> Result<Self, Self::Err> {
+        let root = Element::parse(twiml)?;
+        if root.name != "Response" {
+            return Err(parse_error(format!(
+                "expected <Response>, found <{}>",
+                root.name
+            )));
+        }
+        let verbs = root
+            .children
+            .into_iter()
+            .map(Element::into_verb)
+            .collect::<Result<_, _>>()?;
+        Ok(VoiceResponse { verbs })
+    }
+}
+
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    text: String,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn parse(twiml: &str) -> Result<Self, TwilioError> {
+        let mut stack: Vec<Element> = Vec::new();
+        for event in EventReader::from_str(twiml) {
+            match event.map_err(|e| parse_error(format!("malformed XML: {e}")))? {
+                XmlEvent::StartElement {
+                    name, attributes, ..
+                } => stack.push(Element {
+                    name: name.local_name,
+                    attributes: attributes
+                        .into_iter()
+                        .map(|a| (a.name.local_name, a.value))
+                        .collect(),
+                    text: String::new(),
+                    children: Vec::new(),
+                }),
+                XmlEvent::Characters(text) | XmlEvent::CData(text) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&text);
+                    }
+                }
+                XmlEvent::EndElement { .. } => {
+                    let element = stack.pop().expect("the reader balances elements");
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                _ => {}
+            }
+        }
+        Err(parse_error("missing <Response>".to_string()))
+    }
+
+    fn into_verb(self) -> Result<Verb, TwilioError> {
+        if let Some(child) = self.children.first() {
+            return match self.name.as_str() {
+                "Connect" | "Dial" | "Gather" => Err(self.unsupported()),
+                _ => Err(parse_error(format!(
+                    "unexpected <{}> inside <{}>",
+                    child.name, self.name
+                ))),
+            };
+        }
+        let Element {
+            name,
+            attributes,
+            text,
+            ..
+        } = self;
+        let mut attributes = Attributes {
+            verb: &name,
+            remaining: attributes,
+        };
+        let verb = match name.as_str() {
+            "Say" => Verb::Say(Say {
+                voice: attributes.take("voice"),
+                language: attributes.take("language").map(Into::into),
+                r#loop: attributes.take_parsed("loop")?,
+                ..Say::new(text)
+            }),
+            "Play" => Verb::Play(Play {
+                url: Some(text).filter(|url| !url.is_empty()),
+                r#loop: attributes.take_parsed("loop")?,
+                digits: attributes.take("digits"),
+            }),
+            "Reject" => Verb::Reject(Reject {
+                reason: match attributes.take("reason").as_deref() {
+                    None => None,
+                    Some("rejected") => Some(RejectReason::Rejected),
+                    Some("busy") => Some(RejectReason::Busy),
+                    Some(other) => {
+                        return Err(parse_error(format!("invalid reason `{other}` on <Reject>")))
+                    }
+                },
+            }),
+            "Leave" => Verb::Leave,
+            "Message" => Verb::Message(Message {
+                body: Some(text).filter(|body| !body.is_empty()),
+                to: attributes.take("to"),
+                from: attributes.take("from"),
+                status_callback: attributes.take("statusCallback"),
+            }),
+            "Enqueue" => Verb::Enqueue(Enqueue {
+                action: attributes.take("action"),
+                method: attributes.take("method"),
+                wait_url: attributes.take("waitUrl"),
+                wait_url_method: attributes.take("waitUrlMethod"),
+                workflow_sid: attributes.take("workflowSid"),
+                ..Enqueue::new(text)
+            }),
+            "Connect" | "Dial" | "Gather" => {
+                return Err(parse_error(format!("unsupported verb <{name}>")))
+            }
+            other => return Err(parse_error(format!("unknown verb <{other}>"))),
+        };
+        attributes.finish()?;
+        Ok(verb)
+    }
+
+    fn unsupported(&self) -> TwilioError {
+        parse_error(format!("unsupported verb <{}>", self.name))
+    }
+}
+
+/// Hands out a verb's attributes, so any left over can be reported as unsupported.
+struct Attributes<'a> {
+    verb: &'a str,
+    remaining: Vec<(String, String)>,
+}
+
+impl Attributes<'_> {
+    fn take(&mut self, name: &str) -> Option<String> {
+        let i = self.remaining.iter().position(|(key, _)| key == name)?;
+        Some(self.remaining.remove(i).1)
+    }
+
+    fn take_parsed<T: FromStr>(&mut self, name: &str) -> Result<Option<T>, TwilioError> {
+        self.take(name)
+            .map(|value| {
+                value.parse().map_err(|_| {
+                    parse_error(format!("invalid {name} `{value}` on <{}>", self.verb))
+                })
+            })
+            .transpose()
+    }
+
+    fn finish(self) -> Result<(), TwilioError> {
+        match self.remaining.first() {
+            Some((name, _)) => Err(parse_error(format!(
+                "unsupported attribute `{name}` on <{}>",
+                self.verb
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse_error(message: String) -> TwilioError {
+    TwilioError::TwimlParse(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::twiml::voice::Language;
+
+    fn parse_err(twiml: &str) -> String {
+        match twiml.parse::<VoiceResponse>() {
+            Err(TwilioError::TwimlParse(message)) => message,
+            other => panic!("expected a TwimlParse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn voice_response_from_str_is_round_tripping_flat_verbs() {
+        let response = VoiceResponse::new()
+            .say(Say::new("Please hold").with_language(Language::EnGb))
+            .play(Play::digits("ww1#"))
+            .enqueue(Enqueue {
+                wait_url: Some("https://test.com/hold".into()),
+                ..Enqueue::new("support")
+            })
+            .leave()
+            .reject_with_reason(RejectReason::Busy);
+        let twiml = response.to_string().unwrap();
+
+        let parsed: VoiceResponse = twiml.parse().unwrap();
+
+        assert_eq!(parsed.verbs.len(), 5);
+        assert_eq!(parsed.to_string().unwrap(), twiml);
+    }
+
+    #[test]
+    fn voice_response_from_str_is_erring_when_xml_is_malformed() {
+        let got = parse_err("<Response><Say>Hi</Response>");
+        assert!(got.starts_with("malformed XML"), "{got}");
+
+        let got = parse_err("");
+        assert!(got.starts_with("malformed XML"), "{got}");
+    }
+
+    #[test]
+    fn voice_response_from_str_is_erring_when_verb_is_unknown() {
+        assert_eq!(
+            parse_err("<Response><Foo /></Response>"),
+            "unknown verb <Foo>"
+        );
+        assert_eq!(
+            parse_err("<Response><Dial><Number>415</Number></Dial></Response>"),
+            "unsupported verb <Dial>"
+        );
+        assert_eq!(
+            parse_err("<Say>Hi</Say>"),
+            "expected <Response>, found <Say>"
+        );
+    }
+
+    #[test]
+    fn voice_response_from_str_is_erring_when_attribute_is_invalid() {
+        assert_eq!(
+            parse_err(r#"<Response><Say rate="fast">Hi</Say></Response>"#),
+            "unsupported attribute `rate` on <Say>"
+        );
+        assert_eq!(
+            parse_err(r#"<Response><Play loop="twice">https://a.mp3</Play></Response>"#),
+            "invalid loop `twice` on <Play>"
+        );
+        assert_eq!(
+            parse_err(r#"<Response><Reject reason="hangup" /></Response>"#),
+            "invalid reason `hangup` on <Reject>"
+        );
+    }
+}
@@ -1,12 +1,16 @@
 use super::ToTwiML;
+use crate::callback_urls::require_http;
+pub use crate::endpoints::voice::call::RecordingTrack;
+pub use crate::endpoints::voice::conference::TwilioRegion;
 use crate::error::TwilioError;
 use http::header::CONTENT_TYPE;
 use http::{header::HeaderValue, Response};
 use serde::{Deserialize, Serialize};
+use std::io::Write;
 use strum::Display;
 use twiml_derive::ToTwiML;
 use validator::Validate;
-use xml::writer::{EventWriter, XmlEvent};
+use xml::writer::{EmitterConfig, EventWriter, XmlEvent};
 
 #[derive(Debug, Clone, Default)]
 pub struct VoiceResponse {
@@ -33,11 +37,89 @@ impl VoiceResponse {
         self
     }
 
+    /// Dials into `conference`, taking the `<Dial>` attributes (action, record, ...) from `dial`
+    /// when given. Any noun already set on `dial` is replaced by the conference.
+    pub fn dial_conference(mut self, conference: Conference, dial: Option<Dial>) -> Self {
+        let dial = match dial {
+            Some(dial) => Dial {
+                noun: conference.into(),
+                ..dial
+            },
+            None => Dial::new(conference),
+        };
+        self.verbs.push(Verb::Dial(Box::new(dial)));
+        self
+    }
+
+    /// Dials `number`, sending Twilio to `voicemail_action_url` once the dialed leg ends.
+    ///
+    /// The request to `voicemail_action_url` carries
+    /// [`DialActionParams`](crate::request_parameters::DialActionParams): its
+    /// `dial_call_status` is `busy`, `no-answer` or `failed` when nobody picked up, so reply
+    /// with the voicemail TwiML then, and with an empty response (hanging up) on `completed`.
+    pub fn dial_with_voicemail(
+        self,
+        number: impl Into<String>,
+        voicemail_action_url: impl Into<String>,
+    ) -> Self {
+        self.dial(Dial {
+            action: Some(voicemail_action_url.into()),
+            ..Dial::new(Number::new(number))
+        })
+    }
+
+    /// Places the caller in a queue, where they hear the `wait_url` TwiML until dequeued.
+    pub fn enqueue(mut self, enqueue: impl Into<Enqueue>) -> Self {
+        self.verbs.push(Verb::Enqueue(enqueue.into()));
+        self
+    }
+
+    pub fn gather(mut self, gather: Gather) -> Self {
+        self.verbs.push(Verb::Gather(gather));
+        self
+    }
+
+    /// Takes the caller out of the queue they're in, continuing with the TwiML after the
+    /// `<Enqueue>` that placed them there.
+    pub fn leave(mut self) -> Self {
+        self.verbs.push(Verb::Leave);
+        self
+    }
+
+    /// Replies to an incoming message, see [`VoiceResponse::to_message_response`].
+    pub fn message(mut self, message: impl Into<Message>) -> Self {
+        self.verbs.push(Verb::Message(message.into()));
+        self
+    }
+
+    pub fn play(mut self, play: impl Into<Play>) -> Self {
+        self.verbs.push(Verb::Play(play.into()));
+        self
+    }
+
     pub fn reject(mut self) -> Self {
-        self.verbs.push(Verb::Reject);
+        self.verbs.push(Verb::Reject(Reject::default()));
+        self
+    }
+
+    pub fn reject_with_reason(mut self, reason: RejectReason) -> Self {
+        self.verbs.push(Verb::Reject(Reject::new(reason)));
+        self
+    }
+
+    pub fn say(mut self, say: impl Into<Say>) -> Self {
+        self.verbs.push(Verb::Say(say.into()));
         self
     }
 
+    /// Speaks `text` to the caller, then rejects the call with `reason`.
+    ///
+    /// Saying anything answers the call first, so the caller is billed for it and hears the
+    /// message rather than a busy or not-in-service tone.
+    pub fn say_then_reject(self, text: impl Into<String>, reason: RejectReason) -> Self {
+        self.say(Say::new(text)).reject_with_reason(reason)
+    }
+
     pub fn to_http_response(&self) -> Result<Response<Vec<u8>>, TwilioError> {
         let body = self.to_bytes()?;
         let mut response = Response::new(body);
@@ -47,35 +129,71 @@ impl VoiceResponse {
         Ok(response)
     }
 
+    /// Like [`VoiceResponse::to_http_response`] but for replying to an incoming SMS/MMS
+    /// webhook, so every verb must be a `<Message>`.
+    pub fn to_message_response(&self) -> Result<Response<Vec<u8>>, TwilioError> {
+        if !self
+            .verbs
+            .iter()
+            .all(|verb| matches!(verb, Verb::Message(_)))
+        {
+            return Err(TwilioError::UnsupportedVerb);
+        }
+        self.to_http_response()
+    }
+
     pub fn to_string(&self) -> Result<String, TwilioError> {
         let bytes = self.to_bytes()?;
         Ok(String::from_utf8(bytes)?)
     }
 
     pub fn to_bytes(&self) -> Result<Vec<u8>, TwilioError> {
-        let mut writer = EventWriter::new(Vec::new());
+        self.write_response(EventWriter::new(Vec::new()), |_| Ok(()))
+    }
+
+    /// Like [`VoiceResponse::to_bytes`] but streams the TwiML into `out`, handing it over
+    /// verb by verb so a large response is never buffered whole.
+    ///
+    /// Every verb is validated before anything is written, so a validation error leaves
+    /// `out` untouched; an I/O error part way through can still leave it partially written.
+    pub fn write_to(&self, out: &mut impl Write) -> Result<(), TwilioError> {
+        self.write_response(EventWriter::new(Vec::new()), |buf| {
+            out.write_all(buf).map_err(xml::writer::Error::Io)?;
+            buf.clear();
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    /// Like [`VoiceResponse::to_bytes`] but without the leading `<?xml ...?>` declaration,
+    /// for embedding the TwiML in another document.
+    pub fn to_bytes_without_declaration(&self) -> Result<Vec<u8>, TwilioError> {
+        let writer = EmitterConfig::new()
+            .write_document_declaration(false)
+            .create_writer(Vec::new());
+        self.write_response(writer, |_| Ok(()))
+    }
+
+    /// Validates every verb, then writes the response, passing what's been written so far to
+    /// `flush` after each verb.
+    fn write_response(
+        &self,
+        mut writer: EventWriter<Vec<u8>>,
+        mut flush: impl FnMut(&mut Vec<u8>) -> Result<(), TwilioError>,
+    ) -> Result<Vec<u8>, TwilioError> {
+        for verb in &self.verbs {
+            verb.validate()?;
+        }
+
         writer.write(XmlEvent::start_element("Response"))?;
         for verb in &self.verbs {
-            match &verb {
-                Verb::Connect(noun) => match &noun {
-                    Noun::Stream(stream) => {
-                        stream.validate()?;
-                    }
-                    _ => Err(TwilioError::UnsupportedNoun)?,
-                },
-                Verb::Dial(dial) => match &dial.noun {
-                    Noun::Conference(conference) => {
-                        conference.validate()?;
-                    }
-                    Noun::Number(_) => {}
-                    _ => Err(TwilioError::UnsupportedNoun)?,
-                },
-                Verb::Reject => {}
-            };
             verb.write_xml(&mut writer)?;
+            flush(writer.inner_mut())?;
         }
         writer.write(XmlEvent::end_element())?;
-        Ok(writer.into_inner())
+        let mut rest = writer.into_inner();
+        flush(&mut rest)?;
+        Ok(rest)
     }
 }
 
@@ -85,8 +203,54 @@ pub enum Verb {
     Connect(Noun),
     /// See [Dial](https://www.twilio.com/docs/voice/twiml/dial)
     Dial(Box<Dial>), // Boxed to reduce size
+    /// See [Enqueue](https://www.twilio.com/docs/voice/twiml/enqueue)
+    Enqueue(Enqueue),
+    /// See [Gather](https://www.twilio.com/docs/voice/twiml/gather)
+    Gather(Gather),
+    /// See [Leave](https://www.twilio.com/docs/voice/twiml/leave)
+    Leave,
+    /// See [Message](https://www.twilio.com/docs/messaging/twiml/message)
+    Message(Message),
+    /// See [Play](https://www.twilio.com/docs/voice/twiml/play)
+    Play(Play),
     /// See [Reject](https://www.twilio.com/docs/voice/twiml/reject)
-    Reject,
+    Reject(Reject),
+    /// See [Say](https://www.twilio.com/docs/voice/twiml/say)
+    Say(Say),
+}
+
+impl Verb {
+    fn validate(&self) -> Result<(), TwilioError> {
+        match self {
+            Verb::Connect(noun) => match &noun {
+                Noun::Stream(stream) => {
+                    stream.validate()?;
+                }
+                _ => Err(TwilioError::UnsupportedNoun)?,
+            },
+            Verb::Dial(dial) => match &dial.noun {
+                Noun::Conference(conference) => {
+                    conference.validate()?;
+                }
+                Noun::Application(_) | Noun::Number(_) => {}
+                _ => Err(TwilioError::UnsupportedNoun)?,
+            },
+            Verb::Enqueue(_) | Verb::Leave | Verb::Message(_) => {}
+            Verb::Gather(gather) => {
+                gather.validate()?;
+                for prompt in &gather.prompts {
+                    if let Prompt::Play(play) = prompt {
+                        play.validate()?;
+                    }
+                }
+            }
+            Verb::Play(play) => {
+                play.validate()?;
+            }
+            Verb::Reject(_) | Verb::Say(_) => {}
+        }
+        Ok(())
+    }
 }
 
 impl ToTwiML for Verb {
@@ -99,13 +263,17 @@ impl ToTwiML for Verb {
                 Ok(())
             }
             Verb::Dial(dial) => dial.write_xml(writer),
-
-            // TODO: add attributes to reject
-            Verb::Reject => {
-                writer.write(XmlEvent::start_element("Reject"))?;
+            Verb::Enqueue(enqueue) => enqueue.write_xml(writer),
+            Verb::Gather(gather) => gather.write_xml(writer),
+            Verb::Leave => {
+                writer.write(XmlEvent::start_element("Leave"))?;
                 writer.write(XmlEvent::end_element())?;
                 Ok(())
             }
+            Verb::Message(message) => message.write_xml(writer),
+            Verb::Play(play) => play.write_xml(writer),
+            Verb::Reject(reject) => reject.write_xml(writer),
+            Verb::Say(say) => say.write_xml(writer),
         }
     }
 }
@@ -137,7 +305,7 @@ pub struct Dial {
     #[xml(attribute = "recordingStatusCallbackEvent")]
     pub recording_status_callback_event: Option<String>,
     #[xml(attribute = "recordingTrack")]
-    pub recording_track: Option<String>,
+    pub recording_track: Option<RecordingTrack>,
     #[xml(attribute = "referUrl")]
     pub refer_url: Option<String>,
     #[xml(attribute = "referMethod")]
@@ -178,6 +346,489 @@ impl Dial {
             sequential: None,
         }
     }
+
+    /// Keeps the caller's leg unanswered until the dialed party picks up, so the caller hears
+    /// the dialed party's ringback instead of silence or hold music and isn't billed until the
+    /// call is bridged. Twilio defaults to answering the caller's leg straight away.
+    pub fn with_answer_on_bridge(mut self, answer_on_bridge: bool) -> Self {
+        self.answer_on_bridge = Some(answer_on_bridge);
+        self
+    }
+
+    /// Lets the caller hang up on the dialed party by pressing `*`; only the calling party
+    /// can do this. The caller's call continues with the `action` URL, if set.
+    ///
+    /// When a `time_limit` is set too, whichever comes first ends the dialed leg.
+    pub fn with_hangup_on_star(mut self, hangup_on_star: bool) -> Self {
+        self.hangup_on_star = Some(hangup_on_star);
+        self
+    }
+
+    /// The most seconds the dialed call may last, Twilio defaults to 14400 (four hours).
+    pub fn with_time_limit(mut self, seconds: u32) -> Self {
+        self.time_limit = Some(seconds);
+        self
+    }
+}
+
+#[derive(Debug, Clone, ToTwiML)]
+pub struct Enqueue {
+    #[xml(content)]
+    /// The queue to place the caller in, created if it doesn't exist.
+    pub name: String,
+    #[xml(attribute = "action")]
+    /// Requested with [`EnqueueActionParams`](crate::request_parameters::EnqueueActionParams)
+    /// once the caller leaves the queue.
+    pub action: Option<String>,
+    #[xml(attribute = "method")]
+    pub method: Option<String>,
+    #[xml(attribute = "waitUrl")]
+    /// TwiML played to the caller while waiting, e.g. hold music.
+    pub wait_url: Option<String>,
+    #[xml(attribute = "waitUrlMethod")]
+    pub wait_url_method: Option<String>,
+    #[xml(attribute = "workflowSid")]
+    /// A TaskRouter Workflow to route the call through instead of a plain queue.
+    pub workflow_sid: Option<String>,
+}
+
+impl Enqueue {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            action: None,
+            method: None,
+            wait_url: None,
+            wait_url_method: None,
+            workflow_sid: None,
+        }
+    }
+}
+
+impl From<&str> for Enqueue {
+    fn from(name: &str) -> Self {
+        Enqueue::new(name)
+    }
+}
+
+/// Collects digits or speech from the caller, playing `prompts` while it waits.
+///
+/// Twilio requests `action` with the input once `finish_on_key` is pressed, `num_digits` have
+/// been entered or `timeout` passes without any.
+#[derive(Debug, Clone, ToTwiML, Validate)]
+#[validate(schema(function = "validate_finish_on_key"))]
+#[validate(schema(function = "validate_speech_timeout"))]
+pub struct Gather {
+    #[xml(content)]
+    pub prompts: Vec<Prompt>,
+    #[xml(attribute = "action")]
+    pub action: Option<String>,
+    #[xml(attribute = "method")]
+    pub method: Option<String>,
+    #[xml(attribute = "input")]
+    /// Twilio defaults to `dtmf`.
+    pub input: Option<GatherInput>,
+    #[xml(attribute = "finishOnKey")]
+    /// Twilio defaults to `#`.
+    pub finish_on_key: Option<FinishOnKey>,
+    #[validate(range(min = 1))]
+    #[xml(attribute = "numDigits")]
+    pub num_digits: Option<u32>,
+    #[validate(range(min = 1, max = 600))]
+    #[xml(attribute = "timeout")]
+    /// Seconds to wait for the next digit, Twilio defaults to 5.
+    pub timeout: Option<u32>,
+    #[xml(attribute = "speechTimeout")]
+    /// How long to wait after the caller stops speaking, only for speech input.
+    pub speech_timeout: Option<SpeechTimeout>,
+    #[xml(attribute = "language")]
+    /// The language of the speech to recognize.
+    pub language: Option<Language>,
+}
+
+impl Gather {
+    pub fn new() -> Self {
+        Self {
+            prompts: Vec::new(),
+            action: None,
+            method: None,
+            input: None,
+            finish_on_key: None,
+            num_digits: None,
+            timeout: None,
+            speech_timeout: None,
+            language: None,
+        }
+    }
+
+    pub fn with_prompt(mut self, prompt: impl Into<Prompt>) -> Self {
+        self.prompts.push(prompt.into());
+        self
+    }
+
+    pub fn with_action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    pub fn with_input(mut self, input: GatherInput) -> Self {
+        self.input = Some(input);
+        self
+    }
+
+    pub fn with_finish_on_key(mut self, finish_on_key: FinishOnKey) -> Self {
+        self.finish_on_key = Some(finish_on_key);
+        self
+    }
+
+    pub fn with_num_digits(mut self, num_digits: u32) -> Self {
+        self.num_digits = Some(num_digits);
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_speech_timeout(mut self, speech_timeout: SpeechTimeout) -> Self {
+        self.speech_timeout = Some(speech_timeout);
+        self
+    }
+}
+
+impl Default for Gather {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What a [`Gather`] says or plays to the caller.
+#[derive(Debug, Clone)]
+pub enum Prompt {
+    Play(Play),
+    Say(Say),
+}
+
+impl ToTwiML for Prompt {
+    fn write_xml(&self, writer: &mut EventWriter<Vec<u8>>) -> Result<(), TwilioError> {
+        match self {
+            Prompt::Play(play) => play.write_xml(writer),
+            Prompt::Say(say) => say.write_xml(writer),
+        }
+    }
+}
+
+impl From<Play> for Prompt {
+    fn from(play: Play) -> Self {
+        Prompt::Play(play)
+    }
+}
+
+impl From<Say> for Prompt {
+    fn from(say: Say) -> Self {
+        Prompt::Say(say)
+    }
+}
+
+impl From<&str> for Prompt {
+    fn from(text: &str) -> Self {
+        Prompt::Say(Say::new(text))
+    }
+}
+
+#[derive(Clone, Debug, Display, PartialEq)]
+pub enum GatherInput {
+    #[strum(serialize = "dtmf")]
+    Dtmf,
+    #[strum(serialize = "speech")]
+    Speech,
+    #[strum(serialize = "dtmf speech")]
+    DtmfSpeech,
+}
+
+impl GatherInput {
+    fn includes_speech(&self) -> bool {
+        matches!(self, GatherInput::Speech | GatherInput::DtmfSpeech)
+    }
+}
+
+/// The key that ends a [`Gather`]; [`FinishOnKey::Disabled`] renders an empty `finishOnKey`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FinishOnKey {
+    /// One of `0`-`9`, `*` or `#`.
+    Key(char),
+    /// Every key counts as input, so only `num_digits` or `timeout` ends the gather.
+    Disabled,
+}
+
+impl std::fmt::Display for FinishOnKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FinishOnKey::Key(key) => write!(f, "{key}"),
+            FinishOnKey::Disabled => Ok(()),
+        }
+    }
+}
+
+fn validate_finish_on_key(gather: &Gather) -> Result<(), validator::ValidationError> {
+    match gather.finish_on_key {
+        Some(FinishOnKey::Key(key)) if !key.is_ascii_digit() && !"*#".contains(key) => {
+            let err = validator::ValidationError::new("invalid_finish_on_key")
+                .with_message(format!("Invalid finish on key: {}", key).into());
+            Err(err)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The `speechTimeout` of a [`Gather`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpeechTimeout {
+    /// At least 1 second.
+    Seconds(u32),
+    /// Lets Twilio decide when the caller has stopped speaking.
+    Auto,
+}
+
+impl std::fmt::Display for SpeechTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpeechTimeout::Seconds(seconds) => write!(f, "{seconds}"),
+            SpeechTimeout::Auto => f.write_str("auto"),
+        }
+    }
+}
+
+fn validate_speech_timeout(gather: &Gather) -> Result<(), validator::ValidationError> {
+    let Some(speech_timeout) = &gather.speech_timeout else {
+        return Ok(());
+    };
+    let includes_speech = gather
+        .input
+        .as_ref()
+        .is_some_and(GatherInput::includes_speech);
+    if !includes_speech || *speech_timeout == SpeechTimeout::Seconds(0) {
+        let err = validator::ValidationError::new("invalid_speech_timeout")
+            .with_message(format!("Invalid speech timeout: {}", speech_timeout).into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default, ToTwiML)]
+pub struct Message {
+    #[xml(content)]
+    /// The text of the message.
+    pub body: Option<String>,
+    #[xml(attribute = "to")]
+    /// Defaults to the sender of the incoming message.
+    pub to: Option<String>,
+    #[xml(attribute = "from")]
+    /// Defaults to the number that received the incoming message.
+    pub from: Option<String>,
+    #[xml(attribute = "statusCallback")]
+    pub status_callback: Option<String>,
+}
+
+impl Message {
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            body: Some(body.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_to(mut self, to: impl Into<String>) -> Self {
+        self.to = Some(to.into());
+        self
+    }
+
+    pub fn with_from(mut self, from: impl Into<String>) -> Self {
+        self.from = Some(from.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, ToTwiML)]
+pub struct Reject {
+    #[xml(attribute = "reason")]
+    /// Which tone the caller hears, Twilio defaults to `rejected`.
+    pub reason: Option<RejectReason>,
+}
+
+impl Reject {
+    pub fn new(reason: RejectReason) -> Self {
+        Self {
+            reason: Some(reason),
+        }
+    }
+}
+
+/// The only two tones Twilio accepts for `<Reject reason>`; deserializing anything else fails.
+#[derive(Clone, Debug, Deserialize, Display, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum RejectReason {
+    /// A not-in-service tone.
+    Rejected,
+    /// A busy signal.
+    Busy,
+}
+
+#[derive(Debug, Clone, ToTwiML)]
+pub struct Say {
+    #[xml(content)]
+    pub text: String,
+    #[xml(attribute = "voice")]
+    /// e.g. `man`, `woman`, or an Amazon Polly voice such as `Polly.Joanna`.
+    pub voice: Option<String>,
+    #[xml(attribute = "language")]
+    pub language: Option<Language>,
+    #[xml(attribute = "loop")]
+    /// How many times to repeat the text, 0 loops indefinitely.
+    pub r#loop: Option<u32>,
+}
+
+impl Say {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            voice: None,
+            language: None,
+            r#loop: None,
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Language>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+}
+
+impl From<&str> for Say {
+    fn from(text: &str) -> Self {
+        Say::new(text)
+    }
+}
+
+macro_rules! languages {
+    ($($variant:ident => $code:literal,)+) => {
+        /// A locale for `<Say>` and speech recognition, rendered as its BCP-47 code.
+        ///
+        /// Covers the locales most Twilio voices support; any other code can be given as a
+        /// string, which becomes [`Language::Other`] unless it matches a listed code.
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum Language {
+            $(#[doc = $code] $variant,)+
+            Other(String),
+        }
+
+        impl Language {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Language::$variant => $code,)+
+                    Language::Other(code) => code,
+                }
+            }
+        }
+
+        impl From<&str> for Language {
+            fn from(code: &str) -> Self {
+                match code {
+                    $($code => Language::$variant,)+
+                    other => Language::Other(other.to_string()),
+                }
+            }
+        }
+    };
+}
+
+languages! {
+    DaDk => "da-DK",
+    DeDe => "de-DE",
+    EnAu => "en-AU",
+    EnCa => "en-CA",
+    EnGb => "en-GB",
+    EnIn => "en-IN",
+    EnUs => "en-US",
+    EsEs => "es-ES",
+    EsMx => "es-MX",
+    EsUs => "es-US",
+    FiFi => "fi-FI",
+    FrCa => "fr-CA",
+    FrFr => "fr-FR",
+    HiIn => "hi-IN",
+    ItIt => "it-IT",
+    JaJp => "ja-JP",
+    KoKr => "ko-KR",
+    NbNo => "nb-NO",
+    NlNl => "nl-NL",
+    PlPl => "pl-PL",
+    PtBr => "pt-BR",
+    PtPt => "pt-PT",
+    RuRu => "ru-RU",
+    SvSe => "sv-SE",
+    ZhCn => "zh-CN",
+    ZhHk => "zh-HK",
+    ZhTw => "zh-TW",
+}
+
+impl From<String> for Language {
+    fn from(code: String) -> Self {
+        Language::from(code.as_str())
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, ToTwiML, Validate)]
+pub struct Play {
+    #[xml(content)]
+    /// The URL of an audio file to play. Not needed when only playing `digits`.
+    pub url: Option<String>,
+    #[xml(attribute = "loop")]
+    /// How many times to play the audio, 0 loops indefinitely.
+    pub r#loop: Option<u32>,
+    #[validate(custom(function = "validate_digits"))]
+    #[xml(attribute = "digits")]
+    /// DTMF tones to play: `0`-`9`, `*`, `#`, and `w` to wait half a second.
+    pub digits: Option<String>,
+}
+
+impl Play {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: Some(url.into()),
+            r#loop: None,
+            digits: None,
+        }
+    }
+
+    pub fn digits(digits: impl Into<String>) -> Self {
+        Self {
+            url: None,
+            r#loop: None,
+            digits: Some(digits.into()),
+        }
+    }
+}
+
+fn validate_digits(digits: &str) -> Result<(), validator::ValidationError> {
+    if digits.is_empty()
+        || !digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || "*#w".contains(c))
+    {
+        let err = validator::ValidationError::new("invalid_digits")
+            .with_message(format!("Invalid digits: {}", digits).into());
+        return Err(err);
+    }
+    Ok(())
 }
 
 fn validate_recording_status_callback_event(event: &str) -> Result<(), validator::ValidationError> {
@@ -192,6 +843,7 @@ fn validate_recording_status_callback_event(event: &str) -> Result<(), validator
 
 #[derive(Debug, Clone)]
 pub enum Noun {
+    Application(Application),
     Conference(Box<Conference>), // Boxed to reduce size
     Number(Number),
     Stream(Stream),
@@ -200,6 +852,7 @@ pub enum Noun {
 impl ToTwiML for Noun {
     fn write_xml(&self, writer: &mut EventWriter<Vec<u8>>) -> Result<(), TwilioError> {
         match self {
+            Noun::Application(application) => application.write_xml(writer),
             Noun::Stream(stream) => stream.write_xml(writer),
             Noun::Conference(conference) => conference.write_xml(writer),
             Noun::Number(number) => number.write_xml(writer),
@@ -207,6 +860,57 @@ impl ToTwiML for Noun {
     }
 }
 
+/// See [Application](https://www.twilio.com/docs/voice/twiml/application)
+///
+/// Dials a TwiML App, which must belong to the same account unless its
+/// `public_application_connect_enabled` is set.
+#[derive(Debug, Clone, ToTwiML)]
+pub struct Application {
+    #[xml(content)]
+    pub application_sid: ApplicationSid,
+    #[xml(attribute = "customerId")]
+    /// Identifies the caller to the application, defaults to the caller's identity or number.
+    pub customer_id: Option<String>,
+}
+
+impl Application {
+    pub fn new(application_sid: impl Into<String>) -> Self {
+        Self {
+            application_sid: ApplicationSid {
+                sid: application_sid.into(),
+            },
+            customer_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ApplicationSid {
+    pub sid: String,
+}
+
+// written by hand, the derive needs at least one attribute field
+impl ToTwiML for ApplicationSid {
+    fn write_xml(&self, writer: &mut EventWriter<Vec<u8>>) -> Result<(), TwilioError> {
+        writer.write(XmlEvent::start_element("ApplicationSid"))?;
+        writer.write(XmlEvent::Characters(&self.sid))?;
+        writer.write(XmlEvent::end_element())?;
+        Ok(())
+    }
+}
+
+impl From<Application> for Noun {
+    fn from(application: Application) -> Self {
+        Noun::Application(application)
+    }
+}
+
+impl From<Application> for Dial {
+    fn from(application: Application) -> Self {
+        Dial::new(application)
+    }
+}
+
 #[derive(Debug, Clone, ToTwiML)]
 pub struct Number {
     #[xml(content)]
@@ -295,7 +999,7 @@ pub struct Conference {
     pub record: Option<String>,
 
     #[xml(attribute = "region")]
-    pub region: Option<String>,
+    pub region: Option<TwilioRegion>,
 
     #[xml(attribute = "trim")]
     pub trim: Option<String>,
@@ -377,6 +1081,35 @@ impl Conference {
             recording_status_callback_event: None,
         }
     }
+
+    /// Sets `waitUrl` to an empty string, so participants wait in silence instead of
+    /// hearing Twilio's default hold music.
+    pub fn no_wait_music(mut self) -> Self {
+        self.wait_url = Some(String::new());
+        self
+    }
+
+    /// Sets `statusCallbackEvent` to `events`, space separated.
+    pub fn with_status_callback_events(mut self, events: &[ConferenceStatusEvent]) -> Self {
+        let events: Vec<String> = events.iter().map(ToString::to_string).collect();
+        self.status_callback_event = Some(events.join(" "));
+        self
+    }
+}
+
+/// The conference state changes `statusCallbackEvent` can subscribe to.
+#[derive(Clone, Copy, Debug, Display, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum ConferenceStatusEvent {
+    Start,
+    End,
+    Join,
+    Leave,
+    Mute,
+    Hold,
+    Modify,
+    Speaker,
+    Announcement,
 }
 
 impl From<Conference> for Noun {
@@ -391,21 +1124,29 @@ impl From<Conference> for Dial {
     }
 }
 
-#[derive(Debug, Clone, Validate, ToTwiML)]
+/// See [Stream](https://www.twilio.com/docs/voice/twiml/stream)
+///
+/// These are all the attributes `<Stream>` takes. There is no `statusCallbackEvent`: the
+/// status callback always receives both `stream-started` and `stream-stopped`.
+#[derive(Debug, Clone, ToTwiML)]
 pub struct Stream {
-    #[validate(url, custom(function = "validate_wss_url"))]
     #[xml(attribute = "url")]
+    /// The WebSocket the audio is sent to, it can't have query parameters; use
+    /// `parameters` instead.
     pub url: String,
     #[xml(attribute = "name")]
+    /// Unique per call, lets the stream be stopped by name.
     pub name: Option<String>,
     #[xml(attribute = "track")]
+    /// Defaults to `inbound_track`, the only track a bidirectional `<Connect><Stream>` supports.
     pub track: Option<Track>,
-    #[validate(url)]
     #[xml(attribute = "statusCallback")]
     pub status_callback: Option<String>,
     #[xml(attribute = "statusCallbackMethod")]
+    /// `GET` or `POST`, defaults to `POST`.
     pub status_callback_method: Option<String>,
     #[xml(content)]
+    /// Custom `<Parameter>`s passed along in the stream's `start` message.
     pub parameters: Option<Vec<Parameter>>,
 }
 
@@ -420,6 +1161,19 @@ impl Stream {
             parameters: None,
         }
     }
+
+    /// Errs with [`TwilioError::InvalidWebSocketUrl`] unless `url` is a `wss://` URL, and
+    /// with [`TwilioError::InvalidCallbackUrl`] unless `status_callback` is HTTP(S).
+    pub fn validate(&self) -> Result<(), TwilioError> {
+        match url::Url::parse(&self.url) {
+            Ok(url) if url.scheme() == "wss" => {}
+            _ => return Err(TwilioError::InvalidWebSocketUrl(self.url.clone())),
+        }
+        if let Some(status_callback) = &self.status_callback {
+            require_http(status_callback)?;
+        }
+        Ok(())
+    }
 }
 
 impl From<Stream> for Noun {
@@ -428,15 +1182,6 @@ impl From<Stream> for Noun {
     }
 }
 
-fn validate_wss_url(url: &str) -> Result<(), validator::ValidationError> {
-    if !url.starts_with("wss://") {
-        let err = validator::ValidationError::new("invalid_websocket_url")
-            .with_message("URL must start with 'wss://'".into());
-        return Err(err);
-    }
-    Ok(())
-}
-
 #[derive(Clone, Debug, Deserialize, Display, Serialize)]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -446,6 +1191,16 @@ pub enum Track {
     BothTracks,
 }
 
+impl Track {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Track::InboundTrack => "inbound_track",
+            Track::OutboundTrack => "outbound_track",
+            Track::BothTracks => "both_tracks",
+        }
+    }
+}
+
 #[derive(Clone, Debug, ToTwiML)]
 pub struct Parameter {
     #[xml(attribute = "name")]
@@ -478,6 +1233,25 @@ mod test {
         }
     }
 
+    #[test]
+    fn stream_is_rendering_every_attribute() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Connect><Stream url="wss://test.com/connect" name="agent" track="inbound_track" statusCallback="https://test.com/status" statusCallbackMethod="GET"><Parameter name="customer" value="42" /></Stream></Connect></Response>"#;
+        let stream = Stream {
+            name: Some("agent".into()),
+            track: Some(Track::InboundTrack),
+            status_callback: Some("https://test.com/status".into()),
+            status_callback_method: Some("GET".into()),
+            parameters: Some(vec![Parameter {
+                name: "customer".into(),
+                value: "42".into(),
+            }]),
+            ..Stream::new("wss://test.com/connect")
+        };
+
+        let got = VoiceResponse::new().connect(stream).to_string().unwrap();
+        assert_eq!(got, want);
+    }
+
     #[test]
     fn connect_stream_twiml_is_constructing() {
         let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Connect><Stream url="wss://test.com/connect" /></Connect></Response>"#;
@@ -489,19 +1263,150 @@ mod test {
         assert_eq!(got, want);
     }
 
+    #[test]
+    fn gather_is_rendering_prompts_and_attributes() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Gather action="https://test.com/gather" input="dtmf speech" finishOnKey="*" numDigits="4" timeout="10" speechTimeout="auto"><Say>Enter your PIN</Say></Gather></Response>"#;
+        let gather = Gather::new()
+            .with_prompt("Enter your PIN")
+            .with_action("https://test.com/gather")
+            .with_input(GatherInput::DtmfSpeech)
+            .with_finish_on_key(FinishOnKey::Key('*'))
+            .with_num_digits(4)
+            .with_timeout(10)
+            .with_speech_timeout(SpeechTimeout::Auto);
+
+        let got = VoiceResponse::new().gather(gather).to_string().unwrap();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn gather_is_rendering_empty_finish_on_key() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Gather finishOnKey="" timeout="600" /></Response>"#;
+        let gather = Gather::new()
+            .with_finish_on_key(FinishOnKey::Disabled)
+            .with_timeout(600);
+
+        let got = VoiceResponse::new().gather(gather).to_string().unwrap();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn gather_is_erring_when_attributes_are_out_of_range() {
+        let invalid = [
+            Gather::new().with_timeout(0),
+            Gather::new().with_timeout(601),
+            Gather::new().with_speech_timeout(SpeechTimeout::Auto),
+            Gather::new()
+                .with_input(GatherInput::Dtmf)
+                .with_speech_timeout(SpeechTimeout::Auto),
+            Gather::new()
+                .with_input(GatherInput::Speech)
+                .with_speech_timeout(SpeechTimeout::Seconds(0)),
+            Gather::new().with_num_digits(0),
+            Gather::new().with_finish_on_key(FinishOnKey::Key('a')),
+            Gather::new().with_prompt(Play::digits("12x")),
+        ];
+
+        for gather in invalid {
+            let got = VoiceResponse::new().gather(gather.clone()).to_string();
+            assert!(
+                matches!(got, Err(TwilioError::Validator(_))),
+                "{gather:?} rendered as {got:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn dial_with_voicemail_is_setting_action() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Dial action="https://test.com/voicemail"><Number>415-123-4567</Number></Dial></Response>"#;
+        let got = VoiceResponse::new()
+            .dial_with_voicemail("415-123-4567", "https://test.com/voicemail")
+            .to_string()
+            .unwrap();
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn voice_response_write_to_is_streaming_into_writer() {
+        let response = VoiceResponse::new()
+            .say("Please hold")
+            .play(Play::new("https://api.twilio.com/cowbell.mp3"))
+            .dial(Number::new("415-123-4567"));
+
+        let mut out = std::io::Cursor::new(Vec::new());
+        response.write_to(&mut out).unwrap();
+
+        assert_eq!(out.into_inner(), response.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn voice_response_write_to_is_writing_nothing_when_a_later_verb_is_invalid() {
+        let response = VoiceResponse::new()
+            .say("Please hold")
+            .play(Play::digits("12x"));
+
+        let mut out = Vec::new();
+        let got = response.write_to(&mut out);
+
+        assert!(matches!(got, Err(TwilioError::Validator(_))));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn voice_response_to_bytes_without_declaration_is_omitting_declaration() {
+        let want =
+            r#"<Response><Connect><Stream url="wss://test.com/connect" /></Connect></Response>"#;
+        let got = VoiceResponse::new()
+            .connect(Stream::new("wss://test.com/connect"))
+            .to_bytes_without_declaration()
+            .unwrap();
+
+        assert_eq!(String::from_utf8(got).unwrap(), want);
+    }
+
     #[test]
     fn connect_stream_twiml_is_erring_when_url_is_not_wss() {
         let got = VoiceResponse::new()
             .connect(Stream::new("https://test.com/connect"))
             .to_string();
 
-        assert!(got.is_err());
-        if let Err(e) = got {
-            assert_eq!(
-                e.to_string(),
-                "validation error: url: URL must start with 'wss://'"
-            );
-        }
+        assert!(matches!(
+            got,
+            Err(TwilioError::InvalidWebSocketUrl(url)) if url == "https://test.com/connect"
+        ));
+    }
+
+    #[test]
+    fn connect_stream_twiml_is_erring_when_status_callback_is_not_http() {
+        let stream = Stream {
+            status_callback: Some("test.com/status".into()),
+            ..Stream::new("wss://test.com/connect")
+        };
+        let got = VoiceResponse::new().connect(stream).to_string();
+
+        assert!(matches!(
+            got,
+            Err(TwilioError::InvalidCallbackUrl(url)) if url == "test.com/status"
+        ));
+    }
+
+    #[test]
+    fn stream_validate_is_erring_on_invalid_urls() {
+        let got = Stream::new("https://test.com/connect").validate();
+        assert!(matches!(
+            got,
+            Err(TwilioError::InvalidWebSocketUrl(url)) if url == "https://test.com/connect"
+        ));
+
+        let stream = Stream {
+            status_callback: Some("test.com/status".into()),
+            ..Stream::new("wss://test.com/connect")
+        };
+        assert!(matches!(
+            stream.validate(),
+            Err(TwilioError::InvalidCallbackUrl(url)) if url == "test.com/status"
+        ));
     }
 
     #[test]
@@ -558,6 +1463,56 @@ mod test {
         assert_eq!(response.body(), want.as_bytes());
     }
 
+    #[test]
+    fn enqueue_is_constructing() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Enqueue action="https://test.com/dequeued" waitUrl="https://test.com/hold">support</Enqueue></Response>"#;
+        let enqueue = Enqueue {
+            action: Some("https://test.com/dequeued".into()),
+            wait_url: Some("https://test.com/hold".into()),
+            ..Enqueue::new("support")
+        };
+
+        let got = VoiceResponse::new().enqueue(enqueue).to_string().unwrap();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn leave_is_constructing() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Leave /></Response>"#;
+        let got = VoiceResponse::new().leave().to_string().unwrap();
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn message_is_constructing() {
+        let got = VoiceResponse::new()
+            .message(Message::new("Hi"))
+            .to_bytes_without_declaration()
+            .unwrap();
+        assert_eq!(got, b"<Response><Message>Hi</Message></Response>");
+
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Message to="+15558675310" from="+15017122661">Hi</Message></Response>"#;
+        let message = Message::new("Hi")
+            .with_to("+15558675310")
+            .with_from("+15017122661");
+        let response = VoiceResponse::new()
+            .message(message)
+            .to_message_response()
+            .unwrap();
+        assert_eq!(response.body(), want.as_bytes());
+    }
+
+    #[test]
+    fn message_response_is_erring_when_verb_is_not_message() {
+        let got = VoiceResponse::new()
+            .message(Message::new("Hi"))
+            .reject()
+            .to_message_response();
+
+        assert!(matches!(got, Err(TwilioError::UnsupportedVerb)));
+    }
+
     #[test]
     fn reject_is_constructing() {
         let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Reject /></Response>"#;
@@ -566,6 +1521,108 @@ mod test {
         assert_eq!(got, want);
     }
 
+    #[test]
+    fn reject_is_rendering_each_reason() {
+        let got = VoiceResponse::new()
+            .reject_with_reason(RejectReason::Rejected)
+            .to_bytes_without_declaration()
+            .unwrap();
+        assert_eq!(got, br#"<Response><Reject reason="rejected" /></Response>"#);
+
+        let got = VoiceResponse::new()
+            .reject_with_reason(RejectReason::Busy)
+            .to_bytes_without_declaration()
+            .unwrap();
+        assert_eq!(got, br#"<Response><Reject reason="busy" /></Response>"#);
+    }
+
+    #[test]
+    fn reject_reason_is_accepting_only_twilio_values() {
+        let busy: RejectReason = serde_json::from_str(r#""busy""#).unwrap();
+        assert_eq!(busy, RejectReason::Busy);
+        assert_eq!(
+            serde_json::to_string(&RejectReason::Rejected).unwrap(),
+            r#""rejected""#
+        );
+
+        assert!(serde_json::from_str::<RejectReason>(r#""hangup""#).is_err());
+        assert!(serde_json::from_str::<RejectReason>(r#""Busy""#).is_err());
+    }
+
+    #[test]
+    fn dial_application_is_constructing() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Dial><Application><ApplicationSid>AP123</ApplicationSid></Application></Dial></Response>"#;
+        let got = VoiceResponse::new()
+            .dial(Application::new("AP123"))
+            .to_string()
+            .unwrap();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn connect_application_is_erring() {
+        let got = VoiceResponse::new()
+            .connect(Application::new("AP123"))
+            .to_string();
+        assert!(matches!(got, Err(TwilioError::UnsupportedNoun)));
+    }
+
+    #[test]
+    fn say_is_rendering_language_code() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Say language="es-MX">Hola</Say></Response>"#;
+        let got = VoiceResponse::new()
+            .say(Say::new("Hola").with_language(Language::EsMx))
+            .to_string()
+            .unwrap();
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn language_is_converting_from_codes() {
+        assert_eq!(Language::from("en-US"), Language::EnUs);
+        assert_eq!(Language::from("zh-TW".to_string()), Language::ZhTw);
+        assert_eq!(Language::EnGb.to_string(), "en-GB");
+
+        let other = Language::from("cy-GB");
+        assert_eq!(other, Language::Other("cy-GB".into()));
+        assert_eq!(other.as_str(), "cy-GB");
+    }
+
+    #[test]
+    fn say_then_reject_is_constructing() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Say>We are closed</Say><Reject reason="busy" /></Response>"#;
+        let got = VoiceResponse::new()
+            .say_then_reject("We are closed", RejectReason::Busy)
+            .to_string()
+            .unwrap();
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn dial_with_answer_on_bridge_is_constructing() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Dial answerOnBridge="true"><Number>415-123-4567</Number></Dial></Response>"#;
+        let dial = Dial::new(Number::new("415-123-4567")).with_answer_on_bridge(true);
+
+        let got = VoiceResponse::new().dial(dial).to_string().unwrap();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn dial_with_hangup_on_star_and_time_limit_is_constructing() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Dial action="https://test.com/after" hangupOnStar="true" timeLimit="600"><Number>415-123-4567</Number></Dial></Response>"#;
+        let dial = Dial {
+            action: Some("https://test.com/after".into()),
+            ..Dial::new(Number::new("415-123-4567"))
+        }
+        .with_hangup_on_star(true)
+        .with_time_limit(600);
+
+        let got = VoiceResponse::new().dial(dial).to_string().unwrap();
+        assert_eq!(got, want);
+    }
+
     #[test]
     fn basic_dial_number_is_constructing() {
         let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Dial><Number>415-123-4567</Number></Dial></Response>"#;
@@ -642,6 +1699,26 @@ mod test {
         assert_eq!(got, want);
     }
 
+    #[test]
+    fn conference_with_status_callback_events_is_joining_events() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Dial><Conference statusCallback="https://myapp.com/events" statusCallbackEvent="start end join speaker announcement">EventedConf</Conference></Dial></Response>"#;
+        let conference = Conference {
+            status_callback: Some("https://myapp.com/events".to_string()),
+            ..Conference::new("EventedConf")
+        }
+        .with_status_callback_events(&[
+            ConferenceStatusEvent::Start,
+            ConferenceStatusEvent::End,
+            ConferenceStatusEvent::Join,
+            ConferenceStatusEvent::Speaker,
+            ConferenceStatusEvent::Announcement,
+        ]);
+
+        conference.validate().expect("conference validation failed");
+        let got = VoiceResponse::new().dial(conference).to_string().unwrap();
+        assert_eq!(got, want);
+    }
+
     #[test]
     fn dial_conference_is_erring_when_status_callback_event_is_invalid() {
         let init_conf = Conference::new("EventedConf");
@@ -661,4 +1738,85 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn dial_conference_with_dial_attributes_is_constructing() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Dial action="/after-conference" record="record-from-answer"><Conference startConferenceOnEnter="true" endConferenceOnExit="true">Room 1234</Conference></Dial></Response>"#;
+
+        let conference = Conference {
+            start_conference_on_enter: Some(true),
+            end_conference_on_exit: Some(true),
+            ..Conference::new("Room 1234")
+        };
+        let dial = Dial {
+            action: Some("/after-conference".to_string()),
+            record: Some("record-from-answer".to_string()),
+            ..Dial::new(Conference::new("placeholder"))
+        };
+
+        let got = VoiceResponse::new()
+            .dial_conference(conference, Some(dial))
+            .to_string()
+            .unwrap();
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn dial_conference_without_dial_attributes_is_constructing() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Dial><Conference>Room 1234</Conference></Dial></Response>"#;
+        let got = VoiceResponse::new()
+            .dial_conference(Conference::new("Room 1234"), None)
+            .to_string()
+            .unwrap();
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn dial_conference_no_wait_music_is_emitting_empty_wait_url() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Dial><Conference waitUrl="">Room 1234</Conference></Dial></Response>"#;
+        let got = VoiceResponse::new()
+            .dial(Conference::new("Room 1234").no_wait_music())
+            .to_string()
+            .unwrap();
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn play_digits_is_constructing() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Play digits="wwww3#" /></Response>"#;
+        let got = VoiceResponse::new()
+            .play(Play::digits("wwww3#"))
+            .to_string()
+            .unwrap();
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn play_url_with_loop_is_constructing() {
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?><Response><Play loop="2">https://api.twilio.com/cowbell.mp3</Play></Response>"#;
+        let play = Play {
+            r#loop: Some(2),
+            ..Play::new("https://api.twilio.com/cowbell.mp3")
+        };
+        let got = VoiceResponse::new().play(play).to_string().unwrap();
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn play_digits_is_erring_when_digits_are_invalid() {
+        let got = VoiceResponse::new().play(Play::digits("12a")).to_string();
+
+        assert!(got.is_err());
+        if let Err(e) = got {
+            assert_eq!(
+                e.to_string(),
+                "validation error: digits: Invalid digits: 12a"
+            );
+        }
+    }
 }
@@ -1,4 +1,7 @@
+use crate::endpoints::accounts::notifications::NotificationLog;
 use crate::endpoints::accounts::Status;
+use crate::endpoints::lookups::LookupField;
+use crate::endpoints::usage::UsageCategory;
 use crate::endpoints::voice::call::CallStatus;
 use crate::endpoints::QueryValues;
 
@@ -18,6 +21,17 @@ impl<T> TwilioQuery<T> {
 }
 
 impl<T> TwilioQuery<T> {
+    /// Moves the params to a query for another endpoint, e.g. to reuse paging settings.
+    ///
+    /// Params are carried over as they are, so ones that only make sense for `T`
+    /// (say, a call `Status`) are sent to the new endpoint unchecked.
+    pub fn cast<U>(self) -> TwilioQuery<U> {
+        TwilioQuery {
+            params: self.params,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
     pub fn with_page_size(mut self, page_size: u32) -> Self {
         self.params.push(("PageSize", page_size.to_string()));
         self
@@ -140,3 +154,218 @@ impl<T: ParticipantQueryMarker> TwilioQuery<T> {
         self
     }
 }
+
+pub trait ShortCodeQueryMarker {}
+
+impl<T: ShortCodeQueryMarker> TwilioQuery<T> {
+    /// Only include short codes that match this pattern; partial numbers are matched.
+    pub fn with_short_code(mut self, short_code: impl Into<String>) -> Self {
+        self.params.push(("ShortCode", short_code.into()));
+        self
+    }
+}
+
+pub trait RecordingQueryMarker {}
+
+impl<T: RecordingQueryMarker> TwilioQuery<T> {
+    pub fn with_call_sid(mut self, call_sid: impl Into<String>) -> Self {
+        self.params.push(("CallSid", call_sid.into()));
+        self
+    }
+
+    pub fn with_conference_sid(mut self, conference_sid: impl Into<String>) -> Self {
+        self.params.push(("ConferenceSid", conference_sid.into()));
+        self
+    }
+}
+
+pub trait ByDateCreatedRange {}
+
+impl<T: ByDateCreatedRange> TwilioQuery<T> {
+    /// Only include recordings created on this date, given as YYYY-MM-DD in GMT.
+    pub fn with_date_created_on(mut self, date_created: impl Into<String>) -> Self {
+        self.params.push(("DateCreated", date_created.into()));
+        self
+    }
+
+    /// Only include recordings created on or before midnight of this date (YYYY-MM-DD).
+    pub fn with_date_created_before(mut self, date_created: impl Into<String>) -> Self {
+        self.params.push(("DateCreated<", date_created.into()));
+        self
+    }
+
+    /// Only include recordings created on or after midnight of this date (YYYY-MM-DD).
+    pub fn with_date_created_after(mut self, date_created: impl Into<String>) -> Self {
+        self.params.push(("DateCreated>", date_created.into()));
+        self
+    }
+}
+
+pub trait LookupQueryMarker {}
+
+impl<T: LookupQueryMarker> TwilioQuery<T> {
+    /// The data packages to add to the lookup, each billed separately.
+    pub fn with_fields(mut self, fields: &[LookupField]) -> Self {
+        let fields: Vec<String> = fields.iter().map(ToString::to_string).collect();
+        self.params.push(("Fields", fields.join(",")));
+        self
+    }
+
+    /// The country to read a national-format phone number in, e.g. `US`.
+    pub fn with_country_code(mut self, country_code: impl Into<String>) -> Self {
+        self.params.push(("CountryCode", country_code.into()));
+        self
+    }
+}
+
+pub trait MessageQueryMarker {}
+
+impl<T: MessageQueryMarker> TwilioQuery<T> {
+    /// Only include messages sent on this date, given as YYYY-MM-DD in GMT.
+    pub fn with_date_sent(mut self, date_sent: impl Into<String>) -> Self {
+        self.params.push(("DateSent", date_sent.into()));
+        self
+    }
+
+    /// Only include messages sent on or before this date, given as YYYY-MM-DD in GMT.
+    pub fn with_date_sent_before(mut self, date_sent: impl Into<String>) -> Self {
+        self.params.push(("DateSent<", date_sent.into()));
+        self
+    }
+
+    /// Only include messages sent on or after this date, given as YYYY-MM-DD in GMT.
+    pub fn with_date_sent_after(mut self, date_sent: impl Into<String>) -> Self {
+        self.params.push(("DateSent>", date_sent.into()));
+        self
+    }
+}
+
+pub trait ByPhoneNumber {}
+
+impl<T: ByPhoneNumber> TwilioQuery<T> {
+    /// Only include numbers that match this pattern; `*` matches any single digit.
+    pub fn with_phone_number(mut self, phone_number: impl Into<String>) -> Self {
+        self.params.push(("PhoneNumber", phone_number.into()));
+        self
+    }
+}
+
+pub trait IncomingPhoneNumberQueryMarker {}
+
+impl<T: IncomingPhoneNumberQueryMarker> TwilioQuery<T> {
+    /// Whether to include numbers new to the Twilio platform. Twilio includes them by default.
+    pub fn with_beta(mut self, beta: bool) -> Self {
+        self.params.push(("Beta", beta.to_string()));
+        self
+    }
+}
+
+pub trait AddressQueryMarker {}
+
+impl<T: AddressQueryMarker> TwilioQuery<T> {
+    pub fn with_customer_name(mut self, customer_name: impl Into<String>) -> Self {
+        self.params.push(("CustomerName", customer_name.into()));
+        self
+    }
+
+    /// The ISO country code of the addresses to include, e.g. `DE`.
+    pub fn with_iso_country(mut self, iso_country: impl Into<String>) -> Self {
+        self.params.push(("IsoCountry", iso_country.into()));
+        self
+    }
+
+    pub fn with_emergency_enabled(mut self, emergency_enabled: bool) -> Self {
+        self.params
+            .push(("EmergencyEnabled", emergency_enabled.to_string()));
+        self
+    }
+}
+
+pub trait UsageRecordQueryMarker {}
+
+impl<T: UsageRecordQueryMarker> TwilioQuery<T> {
+    pub fn with_category(mut self, category: impl Into<UsageCategory>) -> Self {
+        self.params.push(("Category", category.into().to_string()));
+        self
+    }
+
+    /// Only include usage on or after this date, given as YYYY-MM-DD or relative, e.g. `-30days`.
+    pub fn with_start_date(mut self, start_date: impl Into<String>) -> Self {
+        self.params.push(("StartDate", start_date.into()));
+        self
+    }
+
+    /// Only include usage on or before this date, given as YYYY-MM-DD or relative, e.g. `+30days`.
+    pub fn with_end_date(mut self, end_date: impl Into<String>) -> Self {
+        self.params.push(("EndDate", end_date.into()));
+        self
+    }
+
+    /// Whether to add the usage of subaccounts. Twilio includes it by default.
+    pub fn with_include_subaccounts(mut self, include_subaccounts: bool) -> Self {
+        self.params
+            .push(("IncludeSubaccounts", include_subaccounts.to_string()));
+        self
+    }
+}
+
+pub trait UsageTriggerQueryMarker {}
+
+impl<T: UsageTriggerQueryMarker> TwilioQuery<T> {
+    pub fn with_usage_category(mut self, usage_category: impl Into<UsageCategory>) -> Self {
+        self.params
+            .push(("UsageCategory", usage_category.into().to_string()));
+        self
+    }
+}
+
+pub trait NotificationQueryMarker {}
+
+impl<T: NotificationQueryMarker> TwilioQuery<T> {
+    pub fn with_log(mut self, log: NotificationLog) -> Self {
+        self.params.push(("Log", log.to_string()));
+        self
+    }
+
+    /// Only include notifications generated on this date, given as YYYY-MM-DD in GMT.
+    pub fn with_message_date(mut self, message_date: impl Into<String>) -> Self {
+        self.params.push(("MessageDate", message_date.into()));
+        self
+    }
+
+    /// Only include notifications generated on or before this date, given as YYYY-MM-DD in GMT.
+    pub fn with_message_date_before(mut self, message_date: impl Into<String>) -> Self {
+        self.params.push(("MessageDate<", message_date.into()));
+        self
+    }
+
+    /// Only include notifications generated on or after this date, given as YYYY-MM-DD in GMT.
+    pub fn with_message_date_after(mut self, message_date: impl Into<String>) -> Self {
+        self.params.push(("MessageDate>", message_date.into()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::voice::call::ListCalls;
+    use crate::endpoints::voice::conference::ListConferences;
+
+    #[test]
+    fn twilio_query_cast_is_keeping_params() {
+        let query: TwilioQuery<ListCalls> = TwilioQuery::new().with_page_size(20).with_page(2);
+
+        let cast: TwilioQuery<ListConferences> = query.clone().cast();
+
+        assert_eq!(cast.params, query.params);
+        assert_eq!(
+            cast.with_conference_status("completed").params,
+            vec![
+                ("PageSize", "20".to_string()),
+                ("Page", "2".to_string()),
+                ("Status", "completed".to_string()),
+            ]
+        );
+    }
+}
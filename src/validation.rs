@@ -1,8 +1,15 @@
 use base64::Engine;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use hmac::{Hmac, Mac};
+use http::header::{CONTENT_TYPE, HOST, UPGRADE};
+use http::uri::PathAndQuery;
 use http::{HeaderMap, Method, Uri};
 use sha1::Sha1;
+use sha2::{Digest, Sha256};
 use std::collections::BTreeMap;
+#[cfg(feature = "chrono")]
+use std::time::Duration;
 
 type HmacSha1 = Hmac<Sha1>;
 
@@ -12,6 +19,10 @@ pub enum SignatureValidationError {
     MissingSignature,
     InvalidSignature,
     HmacError,
+    MissingTimestamp,
+    InvalidTimestamp,
+    StaleTimestamp,
+    InvalidUri,
 }
 
 impl std::fmt::Display for SignatureValidationError {
@@ -21,6 +32,10 @@ impl std::fmt::Display for SignatureValidationError {
             Self::MissingSignature => write!(f, "Missing X-Twilio-Signature header"),
             Self::InvalidSignature => write!(f, "Invalid Twilio signature"),
             Self::HmacError => write!(f, "Error computing HMAC"),
+            Self::MissingTimestamp => write!(f, "Missing Timestamp parameter"),
+            Self::InvalidTimestamp => write!(f, "Invalid Timestamp parameter"),
+            Self::StaleTimestamp => write!(f, "Timestamp is outside the allowed window"),
+            Self::InvalidUri => write!(f, "Invalid request URI"),
         }
     }
 }
@@ -34,9 +49,9 @@ pub fn validate_twilio_signature(
     headers: &HeaderMap,
     post_params: Option<&BTreeMap<String, String>>,
 ) -> Result<(), SignatureValidationError> {
-    // Get host from headers
+    // HeaderMap lookups are case-insensitive, so lowercased headers from proxies are found too
     let host = headers
-        .get("Host")
+        .get(HOST)
         .ok_or(SignatureValidationError::MissingHost)?
         .to_str()
         .map_err(|_| SignatureValidationError::InvalidSignature)?;
@@ -48,7 +63,7 @@ pub fn validate_twilio_signature(
         .to_str()
         .map_err(|_| SignatureValidationError::InvalidSignature)?;
 
-    let scheme = if headers.get("Upgrade").is_some() {
+    let scheme = if headers.get(UPGRADE).is_some() {
         "wss"
     } else {
         "https"
@@ -64,8 +79,9 @@ pub fn validate_twilio_signature(
 
     // For POST requests, add sorted parameters to the validation string
     if method == Method::POST {
-        if let Some(content_type) = headers.get("Content-Type") {
-            let content_type = content_type.to_str().unwrap_or("");
+        if let Some(content_type) = headers.get(CONTENT_TYPE) {
+            // media types are case-insensitive
+            let content_type = content_type.to_str().unwrap_or("").to_ascii_lowercase();
             if content_type.starts_with("application/x-www-form-urlencoded") {
                 if let Some(params) = post_params {
                     for (key, value) in params {
@@ -92,6 +108,93 @@ pub fn validate_twilio_signature(
     Ok(())
 }
 
+/// Prepends `prefix` to the path of `uri`, for when a proxy strips part of the path Twilio
+/// called before the request reaches the application.
+///
+/// Behind AWS API Gateway the Lambda event's `path` omits the stage, so pass the stage
+/// (e.g. `/prod`) here and the `execute-api` (or custom) domain Twilio called as the `Host`
+/// header, so the reconstructed URL matches the one Twilio signed.
+pub fn uri_with_path_prefix(uri: &Uri, prefix: &str) -> Result<Uri, SignatureValidationError> {
+    let prefix = prefix.trim_matches('/');
+    if prefix.is_empty() {
+        return Ok(uri.clone());
+    }
+    let path_and_query = uri.path_and_query().map(|pq| pq.as_str()).unwrap_or("/");
+
+    let mut parts = uri.clone().into_parts();
+    parts.path_and_query = Some(
+        PathAndQuery::try_from(format!("/{prefix}{path_and_query}"))
+            .map_err(|_| SignatureValidationError::InvalidUri)?,
+    );
+    Uri::from_parts(parts).map_err(|_| SignatureValidationError::InvalidUri)
+}
+
+/// The hex-encoded SHA-256 of `body`, which Twilio passes as the `bodySHA256` query
+/// parameter when a webhook has a JSON body instead of form parameters.
+///
+/// The parameter is part of the signed URL, so comparing it with this shows whether a
+/// signature mismatch comes from the body being altered (e.g. re-serialized) on the way in.
+pub fn compute_body_sha256(body: &[u8]) -> String {
+    Sha256::digest(body)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Validates the signature like [`validate_twilio_signature`] and, when `max_age` is set, also
+/// rejects requests whose `Timestamp` parameter is further than `max_age` from now, so a
+/// captured request can't be replayed indefinitely.
+///
+/// Only opt in for webhooks that carry an RFC 2822 `Timestamp` (e.g. call status callbacks
+/// and conference events); requests without one are rejected once `max_age` is set.
+#[cfg(feature = "chrono")]
+pub fn validate_twilio_signature_with_max_age(
+    auth_token: &str,
+    method: &Method,
+    uri: &Uri,
+    headers: &HeaderMap,
+    post_params: Option<&BTreeMap<String, String>>,
+    max_age: Option<Duration>,
+) -> Result<(), SignatureValidationError> {
+    validate_twilio_signature(auth_token, method, uri, headers, post_params)?;
+
+    if let Some(max_age) = max_age {
+        let timestamp =
+            timestamp_param(uri, post_params).ok_or(SignatureValidationError::MissingTimestamp)?;
+        check_timestamp_freshness(&timestamp, max_age, Utc::now())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+fn timestamp_param(uri: &Uri, post_params: Option<&BTreeMap<String, String>>) -> Option<String> {
+    if let Some(timestamp) = post_params.and_then(|params| params.get("Timestamp")) {
+        return Some(timestamp.clone());
+    }
+
+    url::form_urlencoded::parse(uri.query()?.as_bytes())
+        .find(|(key, _)| key == "Timestamp")
+        .map(|(_, value)| value.into_owned())
+}
+
+#[cfg(feature = "chrono")]
+fn check_timestamp_freshness(
+    timestamp: &str,
+    max_age: Duration,
+    now: DateTime<Utc>,
+) -> Result<(), SignatureValidationError> {
+    let timestamp = DateTime::parse_from_rfc2822(timestamp)
+        .map_err(|_| SignatureValidationError::InvalidTimestamp)?;
+
+    // allow the same window either side to tolerate clock skew
+    let age = now.signed_duration_since(timestamp).abs();
+    match age.to_std() {
+        Ok(age) if age <= max_age => Ok(()),
+        _ => Err(SignatureValidationError::StaleTimestamp),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,6 +254,40 @@ mod tests {
         assert!(result.is_ok(), "Valid signature should pass validation");
     }
 
+    #[test]
+    fn compute_body_sha256_is_matching_known_hash() {
+        let body = br#"{"property": "value", "boolean": true}"#;
+
+        assert_eq!(
+            compute_body_sha256(body),
+            "0a1ff7634d9ab3b95db5c9a2dfe9416e41502b283a80c7cf19632632f96e6620"
+        );
+    }
+
+    #[test]
+    fn validate_twilio_signature_is_returning_ok_when_headers_are_lowercased() {
+        let auth_token = "test_auth_token";
+        let uri = Uri::from_static("https://example.com/webhook");
+
+        let mut params = BTreeMap::new();
+        params.insert("CallSid".to_string(), "CA123456789".to_string());
+        let signature =
+            generate_valid_signature(auth_token, "https://example.com/webhook", Some(&params));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("host", "example.com".parse().unwrap());
+        headers.insert("x-twilio-signature", signature.parse().unwrap());
+        headers.insert(
+            "content-type",
+            "Application/X-WWW-Form-Urlencoded".parse().unwrap(),
+        );
+
+        let result =
+            validate_twilio_signature(auth_token, &Method::POST, &uri, &headers, Some(&params));
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+
     #[test]
     fn validate_twilio_signature_is_returning_invalid_signature_when_signature_is_invalid() {
         let auth_token = "test_auth_token";
@@ -305,4 +442,113 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn validate_twilio_signature_is_returning_ok_when_stage_prefix_is_restored() {
+        let auth_token = "test_auth_token";
+        let method = Method::GET;
+        let host = "abc123.execute-api.us-east-1.amazonaws.com";
+        let signature = generate_valid_signature(
+            auth_token,
+            &format!("https://{host}/prod/webhook?CallSid=CA123"),
+            None,
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert("Host", host.parse().unwrap());
+        headers.insert("X-Twilio-Signature", signature.parse().unwrap());
+
+        // API Gateway hands the Lambda the path without the stage
+        let uri = Uri::from_static("/webhook?CallSid=CA123");
+        let result = validate_twilio_signature(auth_token, &method, &uri, &headers, None);
+        assert!(
+            matches!(result, Err(SignatureValidationError::InvalidSignature)),
+            "Stripped stage should fail validation"
+        );
+
+        let uri = uri_with_path_prefix(&uri, "/prod").unwrap();
+        assert_eq!(uri.path(), "/prod/webhook");
+        let result = validate_twilio_signature(auth_token, &method, &uri, &headers, None);
+        assert!(result.is_ok(), "Restored stage should pass validation");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn check_timestamp_freshness_is_returning_ok_when_timestamp_is_fresh() {
+        let now = DateTime::parse_from_rfc2822("Tue, 23 Mar 2021 18:27:35 +0000")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let result = check_timestamp_freshness(
+            "Tue, 23 Mar 2021 18:26:35 +0000",
+            Duration::from_secs(300),
+            now,
+        );
+        assert!(result.is_ok(), "Fresh timestamp should pass validation");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn check_timestamp_freshness_is_returning_stale_timestamp_when_timestamp_is_stale() {
+        let now = DateTime::parse_from_rfc2822("Tue, 23 Mar 2021 18:27:35 +0000")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let result = check_timestamp_freshness(
+            "Tue, 23 Mar 2021 18:17:35 +0000",
+            Duration::from_secs(300),
+            now,
+        );
+        assert!(
+            matches!(result, Err(SignatureValidationError::StaleTimestamp)),
+            "Error should be StaleTimestamp"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn validate_twilio_signature_with_max_age_is_checking_timestamp_param() {
+        let auth_token = "test_auth_token";
+        let uri = Uri::from_static("https://example.com/webhook");
+        let max_age = Some(Duration::from_secs(300));
+
+        for (timestamp, fresh) in [
+            (Utc::now().to_rfc2822(), true),
+            ("Tue, 23 Mar 2021 18:27:35 +0000".to_string(), false),
+        ] {
+            let mut params = BTreeMap::new();
+            params.insert("CallSid".to_string(), "CA123456789".to_string());
+            params.insert("Timestamp".to_string(), timestamp);
+
+            let signature =
+                generate_valid_signature(auth_token, "https://example.com/webhook", Some(&params));
+            let mut headers = HeaderMap::new();
+            headers.insert("Host", "example.com".parse().unwrap());
+            headers.insert("X-Twilio-Signature", signature.parse().unwrap());
+            headers.insert(
+                "Content-Type",
+                "application/x-www-form-urlencoded".parse().unwrap(),
+            );
+
+            let result = validate_twilio_signature_with_max_age(
+                auth_token,
+                &Method::POST,
+                &uri,
+                &headers,
+                Some(&params),
+                max_age,
+            );
+            assert_eq!(result.is_ok(), fresh);
+
+            let result = validate_twilio_signature_with_max_age(
+                auth_token,
+                &Method::POST,
+                &uri,
+                &headers,
+                Some(&params),
+                None,
+            );
+            assert!(result.is_ok(), "Freshness check should be opt-in");
+        }
+    }
 }